@@ -0,0 +1,70 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Generates the tests of the named examples of every day, see `example_tests!`.
+use std::env;
+use std::fs::{create_dir_all, read_dir, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "src/examples/manifest.rs"]
+mod manifest;
+
+/// Returns the numbered entries of `folder` whose name follows `prefix`, e.g. `y2022` or `09`
+fn numbered_entries(folder: &Path, prefix: &str) -> Vec<(u16, PathBuf)> {
+    let Ok(entries) = read_dir(folder) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = entry.file_name().into_string().ok()?;
+            let number = name.strip_prefix(prefix)?.parse().ok()?;
+            entry.path().is_dir().then_some((number, entry.path()))
+        })
+        .collect()
+}
+
+/// Generates the tests of one day
+fn generate(year: u16, day: u16, manifest: &[manifest::Expected]) -> String {
+    manifest
+        .iter()
+        .map(|expected| {
+            let solver = if expected.part == 1 {
+                "super::part_one"
+            } else {
+                "super::part_two"
+            };
+            format!(
+                "#[test]\nfn {}() {{\n    crate::examples::check({year}, {day}, {:?}, {}, {solver});\n}}\n",
+                manifest::test_name(&expected.example, expected.part),
+                expected.example,
+                expected.part
+            )
+        })
+        .collect()
+}
+
+fn main() {
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("examples");
+    create_dir_all(&out).unwrap();
+    // Also catches new seasons, examples and manifests
+    println!("cargo:rerun-if-changed=src");
+
+    for (year, season) in numbered_entries(Path::new("src"), "y") {
+        for (day, examples) in numbered_entries(&season.join("examples"), "") {
+            let path = examples.join(manifest::MANIFEST_FILE);
+            let content = read_to_string(&path).unwrap_or_default();
+            let manifest = manifest::parse_manifest(&content)
+                .unwrap_or_else(|e| panic!("{}, {e}", path.display()));
+
+            write(
+                out.join(format!("{year}_{day}.rs")),
+                generate(year, day, &manifest),
+            )
+            .unwrap();
+        }
+    }
+}
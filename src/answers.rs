@@ -0,0 +1,145 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::helpers::get_path;
+use crate::record::Record;
+use std::fmt::Display;
+use std::fs::{create_dir_all, read_to_string, write};
+
+/// Outcome of comparing an answer with the one recorded in "y{year}/answers/{day}.txt"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer contradicts a recorded answer
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Verdict::Wrong { .. })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✅"),
+            Verdict::Wrong { expected } => write!(f, "❌ (expected: {})", expected),
+            Verdict::Unknown => write!(f, "❔"),
+        }
+    }
+}
+
+/// Reads the recorded answers of `day`. The first line of "y{year}/answers/{day}.txt" holds the
+/// answer to part 1 and the second line the answer to part 2. Missing or empty lines are unknown
+/// answers. Multi-line answers are written on a single line with `\n` separators.
+pub fn read_answers(year: u16, day: u8) -> [Option<String>; 2] {
+    let content = read_to_string(get_path("answers", year, day)).unwrap_or_default();
+    parse_answers(&content)
+}
+
+/// Parses the content of an answers file
+pub fn parse_answers(content: &str) -> [Option<String>; 2] {
+    let mut lines = content.lines().map(|line| {
+        let line = line.trim();
+        if line.is_empty() {
+            None
+        } else {
+            Some(line.replace("\\n", "\n"))
+        }
+    });
+
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+/// Formats answers as the content of an answers file
+pub fn format_answers(answers: &[Option<String>; 2]) -> String {
+    let lines: Vec<String> = answers
+        .iter()
+        .map(|answer| answer.as_deref().unwrap_or_default().replace('\n', "\\n"))
+        .collect();
+
+    format!("{}\n", lines.join("\n").trim_end())
+}
+
+/// Records `answer` as the answer to `part` of `day` in "y{year}/answers/{day}.txt"
+pub fn write_answer(year: u16, day: u8, part: u8, answer: &str) -> std::io::Result<()> {
+    let path = get_path("answers", year, day);
+    let mut answers = read_answers(year, day);
+    answers[usize::from(part.clamp(1, 2)) - 1] = Some(answer.to_string());
+
+    if let Some(folder) = path.parent() {
+        create_dir_all(folder)?;
+    }
+    write(path, format_answers(&answers))
+}
+
+/// Compares an `answer` to the `expected` one
+pub fn compare(answer: Option<&str>, expected: Option<&str>) -> Verdict {
+    match (answer, expected) {
+        (_, None) => Verdict::Unknown,
+        (Some(answer), Some(expected)) if answer.trim_end() == expected.trim_end() => {
+            Verdict::Correct
+        }
+        (_, Some(expected)) => Verdict::Wrong {
+            expected: expected.to_string(),
+        },
+    }
+}
+
+/// Checks a `Record` against the recorded answers of its day
+pub fn check(record: &Record) -> Verdict {
+    let answers = read_answers(record.year, record.day);
+    let expected = match record.part {
+        1 => answers[0].as_deref(),
+        2 => answers[1].as_deref(),
+        _ => None,
+    };
+
+    compare(record.answer.as_deref(), expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("157\n70\n"),
+            [Some("157".into()), Some("70".into())]
+        );
+        assert_eq!(parse_answers("\nMCD"), [None, Some("MCD".into())]);
+        assert_eq!(parse_answers("##.\\n.##"), [Some("##.\n.##".into()), None]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_format_answers() {
+        for answers in [
+            [Some("157".into()), Some("70".into())],
+            [None, Some("MCD".into())],
+            [Some("##.\n.##".into()), None],
+        ] {
+            assert_eq!(parse_answers(&format_answers(&answers)), answers);
+        }
+        assert_eq!(format_answers(&[Some("157".into()), None]), "157\n");
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare(Some("24"), Some("24")), Verdict::Correct);
+        assert_eq!(compare(Some("#.\n"), Some("#.")), Verdict::Correct);
+        assert_eq!(
+            compare(Some("25"), Some("24")),
+            Verdict::Wrong {
+                expected: "24".into()
+            }
+        );
+        assert!(compare(None, Some("24")).is_regression());
+        assert_eq!(compare(Some("24"), None), Verdict::Unknown);
+        assert_eq!(compare(None, None), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Arguments shared by the day binaries.
+use crate::days;
+use crate::helpers::try_read_input;
+use crate::record::RECORD_FLAG;
+use crate::runner::DEFAULT_TIMEOUT;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Parses the value of `--timeout <seconds>`. `--timeout 0` disables the timeout, which is
+/// `DEFAULT_TIMEOUT` when the flag is missing.
+pub fn parse_timeout(value: &str) -> Result<Option<Duration>, String> {
+    let invalid = || format!("invalid timeout \"{value}\", expected a number of seconds");
+    let seconds: f64 = value.parse().map_err(|_| invalid())?;
+    if seconds == 0.0 {
+        return Ok(None);
+    }
+
+    // Rejects negative, infinite and NaN values, and values too large for a `Duration`
+    Duration::try_from_secs_f64(seconds)
+        .map(Some)
+        .map_err(|_| invalid())
+}
+
+/// Where the puzzle input of a run comes from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The input file of the day, "y{year}/inputs/{day}.txt"
+    Default,
+    /// Any file, e.g. the input of a colleague or a generated stress input
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads the input of `day` of `year` from the source
+    pub fn read(&self, year: u16, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::Default => try_read_input(year, day),
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Default => write!(f, "input file"),
+            InputSource::File(path) => write!(f, "\"{}\"", path.display()),
+            InputSource::Stdin => write!(f, "standard input"),
+        }
+    }
+}
+
+/// Arguments of a day binary, e.g. `cargo solve 07 -- --input path/to/file --part 2`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveArgs {
+    /// `--year <year>`, the latest season by default
+    pub year: u16,
+    /// `--input <path>` or `--stdin`
+    pub input: InputSource,
+    /// `--part 1|2`, both parts by default
+    pub part: Option<u8>,
+    /// `--variants`: compares the implementations of each part instead of solving it
+    pub variants: bool,
+    /// `--timeout <seconds>`: how long each variant may run, see `parse_timeout`
+    pub timeout: Option<Duration>,
+}
+
+impl SolveArgs {
+    /// Parses the arguments the binary was started with
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(std::env::args_os().skip(1).collect())
+    }
+
+    /// Parses `args`, the program name excluded. Unknown arguments are rejected.
+    pub fn parse(args: Vec<OsString>) -> Result<Self, String> {
+        let mut args = pico_args::Arguments::from_vec(args);
+        let error = |e: pico_args::Error| e.to_string();
+
+        let year = args
+            .opt_value_from_str(["-y", "--year"])
+            .map_err(error)?
+            .unwrap_or_else(days::latest_year);
+        let part = args.opt_value_from_str(["-p", "--part"]).map_err(error)?;
+        let path: Option<PathBuf> = args
+            .opt_value_from_os_str(["-i", "--input"], |path| {
+                Ok::<_, String>(PathBuf::from(path))
+            })
+            .map_err(error)?;
+        let stdin = args.contains("--stdin");
+        let variants = args.contains("--variants");
+        let timeout = args
+            .opt_value_from_fn("--timeout", parse_timeout)
+            .map_err(error)?
+            .unwrap_or(Some(DEFAULT_TIMEOUT));
+        // Read by `record::is_enabled`
+        args.contains(RECORD_FLAG);
+
+        let remaining = args.finish();
+        if let Some(argument) = remaining.first() {
+            return Err(format!("unexpected argument {argument:?}"));
+        }
+        if let Some(part) = part.filter(|part| !matches!(part, 1 | 2)) {
+            return Err(format!("invalid part {part}, expected 1 or 2"));
+        }
+
+        let input = match (path, stdin) {
+            (Some(_), true) => return Err(String::from("--input and --stdin cannot be combined")),
+            (Some(path), false) => InputSource::File(path),
+            (None, true) => InputSource::Stdin,
+            (None, false) => InputSource::Default,
+        };
+
+        Ok(Self {
+            year,
+            input,
+            part,
+            variants,
+            timeout,
+        })
+    }
+
+    /// The parts to run, in order
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<SolveArgs, String> {
+        SolveArgs::parse(args.iter().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(&[]),
+            Ok(SolveArgs {
+                year: days::latest_year(),
+                input: InputSource::Default,
+                part: None,
+                variants: false,
+                timeout: Some(DEFAULT_TIMEOUT),
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "--input",
+                "stress.txt",
+                "--part",
+                "2",
+                "--year",
+                "2021",
+                "--record",
+                "--timeout",
+                "1.5"
+            ]),
+            Ok(SolveArgs {
+                year: 2021,
+                input: InputSource::File(PathBuf::from("stress.txt")),
+                part: Some(2),
+                variants: false,
+                timeout: Some(Duration::from_millis(1500)),
+            })
+        );
+        assert_eq!(parse(&["--stdin"]).unwrap().input, InputSource::Stdin);
+        assert!(parse(&["--variants"]).unwrap().variants);
+        assert_eq!(parse(&["--timeout", "0"]).unwrap().timeout, None);
+        assert_eq!(parse(&["-p", "1"]).unwrap().parts(), vec![1]);
+        assert_eq!(parse(&[]).unwrap().parts(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--input", "a.txt", "--stdin"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["--year"]).is_err());
+        assert!(parse(&["--timeout", "inf"]).is_err());
+        assert!(parse(&["--timeout", "NaN"]).is_err());
+        assert!(parse(&["--timeout", "-1"]).is_err());
+        assert!(parse(&["--timeout", "1e300"]).is_err());
+    }
+}
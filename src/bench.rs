@@ -0,0 +1,165 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::fs;
+use std::time::Duration;
+
+/// Marker comment surrounding the timing table in the readme
+pub const README_MARKER: &str = "<!--- benchmarking table --->";
+
+/// Summary statistics over repeated timings of the same part
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes `Stats` over `samples`. Returns `None` when there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let runs = sorted.len();
+        let middle = runs / 2;
+        let median = if runs % 2 == 0 {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?} ({} runs)",
+            self.min, self.median, self.mean, self.stddev, self.runs
+        )
+    }
+}
+
+/// Median timings of a day, `None` for unsolved parts
+pub struct Timings {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+impl Timings {
+    fn total(&self) -> Duration {
+        self.part_one.unwrap_or_default() + self.part_two.unwrap_or_default()
+    }
+}
+
+/// Renders `timings` as a Markdown table
+pub fn timing_table(timings: &[Timings]) -> String {
+    let cell = |timing: Option<Duration>| match timing {
+        Some(timing) => format!("`{timing:.2?}`"),
+        None => String::from("-"),
+    };
+
+    let mut table =
+        String::from("| Day | Part 1 | Part 2 | Total |\n| :---: | :---: | :---: | :---: |\n");
+    for timing in timings {
+        table.push_str(&format!(
+            "| [Day {}](./src/y{}/day{:02}.rs) | {} | {} | `{:.2?}` |\n",
+            timing.day,
+            timing.year,
+            timing.day,
+            cell(timing.part_one),
+            cell(timing.part_two),
+            timing.total()
+        ));
+    }
+
+    let total: Duration = timings.iter().map(Timings::total).sum();
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    ));
+
+    table
+}
+
+/// Replaces whatever sits between the two `README_MARKER` comments of `readme` by `table`.
+/// Returns `None` if the markers are missing.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(README_MARKER)? + README_MARKER.len();
+    let end = start + readme[start..].find(README_MARKER)?;
+
+    Some(format!(
+        "{}\n\n## Benchmarks\n\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+/// Writes `timings` into the readme at `path`
+pub fn update_readme(path: &str, timings: &[Timings]) -> Result<(), String> {
+    let readme = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let readme = replace_table(&readme, &timing_table(timings))
+        .ok_or_else(|| format!("could not find two \"{README_MARKER}\" markers"))?;
+
+    fs::write(path, readme).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_micros).to_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev, Duration::from_nanos(1118));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!("# AoC\n{README_MARKER}\nold\n{README_MARKER}\n## Usage\n");
+        let table = timing_table(&[Timings {
+            year: 2022,
+            day: 1,
+            part_one: Some(Duration::from_micros(30)),
+            part_two: None,
+        }]);
+        let updated = replace_table(&readme, &table).unwrap();
+
+        assert!(!updated.contains("old"));
+        assert!(updated.contains("| [Day 1](./src/y2022/day01.rs) | `30.00µs` | - | `30.00µs` |"));
+        assert!(updated.ends_with(&format!("**Total: 0.03ms**\n\n{README_MARKER}\n## Usage\n")));
+        assert_eq!(replace_table(&updated, &table).unwrap(), updated);
+        assert_eq!(replace_table("# AoC", &table), None);
+    }
+}
@@ -2,7 +2,8 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::client::{self, Client};
+use advent_of_code::days;
 use std::process;
 
 struct Args {
@@ -27,20 +28,11 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let result = Client::from_env().and_then(|client| client::download(&client, year, args.day));
 
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
-            process::exit(1);
-        }
+    if let Err(e) = result {
+        eprintln!("Failed to download day {}: {e}", args.day);
+        process::exit(1);
     }
 }
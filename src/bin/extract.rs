@@ -0,0 +1,200 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::days;
+use advent_of_code::examples::puzzle::{answer_between, answer_candidates, code_blocks};
+use advent_of_code::examples::{self, Expected};
+use advent_of_code::helpers::{get_examples_dir, get_path, try_read_puzzle};
+use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::PathBuf;
+use std::process;
+
+/// Number of lines shown for each code block
+const PREVIEW_LINES: usize = 4;
+
+/// A code block to write, `--block <index>[=<name>]`
+struct Choice {
+    index: usize,
+    /// Name of the example, the default example file of the day when missing
+    name: Option<String>,
+}
+
+struct Args {
+    day: u8,
+    year: u16,
+    choices: Vec<Choice>,
+}
+
+fn parse_choice(choice: &str) -> Result<Choice, String> {
+    let (index, name) = match choice.split_once('=') {
+        Some((index, name)) => (index, Some(name.to_string())),
+        None => (choice, None),
+    };
+    let index = index
+        .parse()
+        .map_err(|_| format!("invalid block \"{choice}\", expected <index>[=<name>]"))?;
+    if let Some(name) = name
+        .as_deref()
+        .filter(|name| !examples::is_valid_name(name))
+    {
+        return Err(format!(
+            "invalid example name \"{name}\", it may only hold letters, digits, '_' and '-'"
+        ));
+    }
+
+    Ok(Choice { index, name })
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::latest_year),
+        choices: args.values_from_fn(["-b", "--block"], parse_choice)?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Writes `text` to `path` unless the file already holds an example
+fn write_example(path: &PathBuf, text: &str) -> Result<(), String> {
+    if read_to_string(path).is_ok_and(|content| !content.trim().is_empty()) {
+        return Err(format!("\"{}\" already holds an example", path.display()));
+    }
+    if let Some(folder) = path.parent() {
+        create_dir_all(folder).map_err(|e| e.to_string())?;
+    }
+    write(path, text).map_err(|e| e.to_string())
+}
+
+fn main() {
+    let Args { day, year, choices } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let puzzle = match try_read_puzzle(year, day) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("Could not read the puzzle of day {day:02}, run `cargo download {day:02}` first: {e}");
+            process::exit(1);
+        }
+    };
+    let blocks = code_blocks(&puzzle);
+    let candidates = answer_candidates(&puzzle);
+
+    if choices.is_empty() {
+        for (index, block) in blocks.iter().enumerate() {
+            let lines: Vec<&str> = block.text.lines().collect();
+            println!(
+                "{ANSI_BOLD}Block {}{ANSI_RESET} {ANSI_ITALIC}(line {}, {} lines){ANSI_RESET}",
+                index + 1,
+                block.line,
+                lines.len()
+            );
+            for line in lines.iter().take(PREVIEW_LINES) {
+                println!("    {line}");
+            }
+            if lines.len() > PREVIEW_LINES {
+                println!("    …");
+            }
+        }
+
+        for part in 1..=2 {
+            let answers: Vec<String> = candidates
+                .iter()
+                .filter(|candidate| candidate.part == part)
+                .map(|candidate| format!("{} (line {})", candidate.answer, candidate.line))
+                .collect();
+            println!(
+                "{ANSI_BOLD}Part {part} answers:{ANSI_RESET} {}",
+                answers.join(", ")
+            );
+        }
+
+        println!("---");
+        println!("🎄 Type `cargo examples {day:02} --block <index>[=<name>]` to write examples.");
+        return;
+    }
+
+    if choices
+        .iter()
+        .filter(|choice| choice.name.is_none())
+        .count()
+        > 1
+    {
+        eprintln!("Only one block can be written to the default example file, name the others.");
+        process::exit(1);
+    }
+    if let Some(choice) = choices
+        .iter()
+        .find(|choice| choice.index == 0 || choice.index > blocks.len())
+    {
+        eprintln!(
+            "Invalid block {}, the puzzle has {} blocks.",
+            choice.index,
+            blocks.len()
+        );
+        process::exit(1);
+    }
+
+    let mut failed = false;
+    for choice in &choices {
+        let block = &blocks[choice.index - 1];
+        let path = match &choice.name {
+            Some(name) => get_examples_dir(year, day).join(format!("{name}.txt")),
+            None => get_path("examples", year, day),
+        };
+        match write_example(&path, &block.text) {
+            Ok(_) => println!("Wrote block {} to \"{}\"", choice.index, path.display()),
+            Err(e) => {
+                eprintln!("Failed to write block {}: {e}", choice.index);
+                failed = true;
+                continue;
+            }
+        }
+
+        let Some(name) = &choice.name else {
+            continue;
+        };
+        // The answers of an example are given before the next chosen example
+        let next = choices
+            .iter()
+            .map(|other| blocks[other.index - 1].line)
+            .filter(|line| *line > block.line)
+            .min()
+            .unwrap_or(usize::MAX);
+        for part in 1..=2 {
+            let Some(answer) = answer_between(&candidates, part, block.line, next) else {
+                continue;
+            };
+            let expected = Expected {
+                example: name.clone(),
+                part,
+                answer: answer.to_string(),
+            };
+            match examples::add_expected(year, day, &expected) {
+                Ok(true) => println!(
+                    "Expecting {answer} for part {part} of \"{name}\", check it in \"{}\"",
+                    get_examples_dir(year, day)
+                        .join(examples::MANIFEST_FILE)
+                        .display()
+                ),
+                Ok(false) => {}
+                Err(e) => {
+                    eprintln!("Failed to record the answer of part {part}: {e}");
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
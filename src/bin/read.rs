@@ -2,7 +2,9 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::client::Client;
+use advent_of_code::helpers::try_read_puzzle;
+use advent_of_code::{days, html, markdown};
 use std::process;
 
 struct Args {
@@ -27,19 +29,21 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let year = args.year.unwrap_or_else(days::latest_year);
+    if let Ok(puzzle) = try_read_puzzle(year, args.day) {
+        println!("{}", markdown::render(&puzzle, markdown::terminal_width()));
+        return;
     }
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    let puzzle = Client::from_env().and_then(|client| client.fetch_puzzle(year, args.day));
+
+    match puzzle {
+        Ok(page) => println!(
+            "{}",
+            markdown::render(&html::to_markdown(&page), markdown::terminal_width())
+        ),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+            eprintln!("Failed to fetch puzzle for day {}: {e}", args.day);
             process::exit(1);
         }
     }
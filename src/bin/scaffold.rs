@@ -2,87 +2,385 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::days;
+use advent_of_code::examples::MANIFEST_FILE;
+use advent_of_code::helpers::{get_examples_dir, get_path, get_season_path, try_read_puzzle};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{create_dir_all, read_to_string, write, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"use advent_of_code::helpers::*;
-use advent_of_code::solve;
+/// Built-in module templates, overridden by ".aoc/{name}.rs"
+const PRESETS: [(&str, &str); 4] = [
+    ("plain", include_str!("../../templates/plain.rs")),
+    ("grid", include_str!("../../templates/grid.rs")),
+    ("fromstr", include_str!("../../templates/fromstr.rs")),
+    ("simulation", include_str!("../../templates/simulation.rs")),
+];
 
-fn main() {
-    let input = &read_input(DAY);
-    solve!(1, solve_part_1, input);
-    solve!(2, solve_part_2, input);
+/// Template used without `--template`, the "plain" preset unless ".aoc/template.rs" exists
+const DEFAULT_TEMPLATE: &str = "template";
+
+const TEMPLATES_PATH: &str = ".aoc";
+
+const MANIFEST_TEMPLATE: &str = "# example part answer\n";
+
+const SEASON_TEMPLATE: &str = r###"/*
+ * This file contains template code.
+ * New days are registered in the `days!` invocation below. `cargo scaffold` does that for you.
+ */
+use crate::days::days;
+
+days! {
+    year YEAR;
 }
+"###;
+
+const BIN_TEMPLATE: &str = "advent_of_code::solution!(DAY);\n";
 
-fn solve_part_1(input: &str) -> Option<u64> {
-    None
+const LIB_PATH: &str = "src/lib.rs";
+
+const SEASONS_PATH: &str = "src/days/mod.rs";
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Name of the module template, a preset or a file of `TEMPLATES_PATH`
+    template: String,
+    /// Type of the answers returned by the generated solvers
+    answer: String,
+    /// Adds the missing files of an existing day instead of failing
+    update: bool,
 }
 
-fn solve_part_2(input: &str) -> Option<u64> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::latest_year),
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string()),
+        answer: args
+            .opt_value_from_str(["-a", "--answer"])?
+            .unwrap_or_else(|| String::from("u64")),
+        update: args.contains(["-u", "--update"]) | args.contains(["-f", "--force"]),
+        day: args.free_from_str()?,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Returns the module template called `name`: ".aoc/{name}.rs" if it exists, a preset otherwise
+fn load_template(name: &str) -> Result<String, String> {
+    let path = Path::new(TEMPLATES_PATH).join(format!("{name}.rs"));
+    if let Ok(template) = read_to_string(&path) {
+        return Ok(template);
+    }
 
-    #[test]
-    fn test() {}
+    let name = if name == DEFAULT_TEMPLATE {
+        "plain"
+    } else {
+        name
+    };
+    PRESETS
+        .iter()
+        .find(|(preset, _)| *preset == name)
+        .map(|(_, template)| template.to_string())
+        .ok_or_else(|| {
+            let presets: Vec<&str> = PRESETS.iter().map(|(preset, _)| *preset).collect();
+            format!(
+                "no template \"{}\" nor preset \"{name}\", presets are {}",
+                path.display(),
+                presets.join(", ")
+            )
+        })
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Returns the title of the puzzle of `day`, e.g. "Day 9: Rope Bridge", if it was downloaded
+fn puzzle_title(year: u16, day: u8) -> Option<String> {
+    let puzzle = try_read_puzzle(year, day).ok()?;
+    let heading = puzzle.lines().next()?;
+    let title = heading.trim_start_matches('\\').trim_matches('-').trim();
+    title.starts_with("Day ").then(|| title.to_string())
+}
+
+/// Replaces the `{{day}}`, `{{day_padded}}`, `{{year}}`, `{{title}}` and `{{answer}}`
+/// placeholders of `template`
+fn render(template: &str, day: u8, year: u16, title: &str, answer: &str) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{day_padded}}", &format!("{day:02}"))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{title}}", title)
+        .replace("{{answer}}", answer)
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+/// What `add_tests` did to the module of an existing day
+enum AddedTests {
+    /// Appended the tests module of the template, the module had none
+    Module,
+    /// Inserted the `example_tests!` call missing from the tests module
+    ExampleTests,
+    /// Nothing was missing
+    Nothing,
+}
+
+/// Appends the tests module of `module` to the existing module at `path` if it has none, or
+/// inserts the `example_tests!` call of the day in its tests module when it is missing, so that
+/// the answers of the manifest are tested.
+fn add_tests(path: &Path, module: &str, year: u16, day: u8) -> Result<AddedTests, std::io::Error> {
+    let code = read_to_string(path)?;
+    let Some(start) = module.find("#[cfg(test)]") else {
+        return Ok(AddedTests::Nothing);
+    };
+    if !code.contains("#[cfg(test)]") {
+        write(path, format!("{}\n\n{}", code.trim_end(), &module[start..]))?;
+        return Ok(AddedTests::Module);
+    }
+
+    match insert_example_tests(&code, year, day) {
+        Some(code) => {
+            write(path, code)?;
+            Ok(AddedTests::ExampleTests)
+        }
+        None => Ok(AddedTests::Nothing),
+    }
+}
+
+/// Inserts `crate::example_tests!(year, day);` after the `use` lines that open the tests module
+/// of `code`. Returns `None` when the module calls it already, or has no tests module.
+fn insert_example_tests(code: &str, year: u16, day: u8) -> Option<String> {
+    if code.contains("example_tests!") {
+        return None;
+    }
+    let opening = code.find("mod tests {")?;
+    let mut at = opening + code[opening..].find('\n')? + 1;
+    let mut uses = false;
+    for line in code[at..].split_inclusive('\n') {
+        if !line.trim_start().starts_with("use ") {
+            break;
+        }
+        at += line.len();
+        uses = true;
+    }
+
+    let call = format!("    crate::example_tests!({year}, {day});\n");
+    let call = if uses {
+        format!("\n{call}")
+    } else {
+        format!("{call}\n")
+    };
+    Some(format!("{}{call}{}", &code[..at], &code[at..]))
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(folder) = path.parent() {
+        create_dir_all(folder)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Adds `entry` under `key` to the `$invocation! { ... }` of the file at `path` unless `key` is
+/// registered already, keeping entries sorted. Lines of the invocation that are not
+/// `key => module,` entries are kept first.
+fn register(path: &Path, invocation: &str, key: u16, entry: String) -> Result<(), std::io::Error> {
+    let registry = read_to_string(path)?;
+    let opening = format!("{invocation}! {{\n");
+    let (head, rest) = registry
+        .split_once(&opening)
+        .ok_or_else(|| std::io::Error::other(format!("`{invocation}!` invocation not found")))?;
+    let (lines, tail) = rest.split_once("}\n").ok_or_else(|| {
+        std::io::Error::other(format!("`{invocation}!` invocation is not closed"))
+    })?;
+
+    let mut preamble = vec![];
+    let mut entries: Vec<(u16, String)> = vec![];
+    for line in lines.lines() {
+        let registered = line
+            .trim()
+            .split_once(" => ")
+            .and_then(|(key, _)| key.parse().ok());
+        match registered {
+            Some(registered) => entries.push((registered, line.to_string())),
+            None => preamble.push(line.to_string()),
+        }
+    }
+    if !entries.iter().any(|(registered, _)| *registered == key) {
+        entries.push((key, entry));
+    }
+    entries.sort_by_key(|(key, _)| *key);
+
+    let lines: Vec<String> = preamble
+        .into_iter()
+        .chain(entries.into_iter().map(|(_, line)| line))
+        .collect();
+    let registry = format!("{head}{opening}{}\n}}\n{tail}", lines.join("\n"));
+
+    write(path, registry)
+}
+
+/// Creates the folder and registry of the season of `year`, and declares it in the library
+fn create_season(year: u16) -> Result<(), std::io::Error> {
+    let season_path = get_season_path(year);
+    create_dir_all(&season_path)?;
+    safe_create_file(&season_path.join("mod.rs"))?.write_all(
+        SEASON_TEMPLATE
+            .replace("YEAR", &year.to_string())
+            .as_bytes(),
+    )?;
+
+    let lib = read_to_string(LIB_PATH)?;
+    let declaration = format!("pub mod y{year};\n");
+    if !lib.contains(&declaration) {
+        // Declared right after the last module, season modules sort after the others
+        let end = lib
+            .match_indices("pub mod ")
+            .last()
+            .and_then(|(start, _)| lib[start..].find('\n').map(|end| start + end + 1))
+            .unwrap_or_default();
+        write(
+            LIB_PATH,
+            format!("{}{declaration}{}", &lib[..end], &lib[end..]),
+        )?;
+    }
+
+    register(
+        Path::new(SEASONS_PATH),
+        "seasons",
+        year,
+        format!("    {year} => y{year},"),
+    )
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            eprintln!(
+                "Need to specify a day (as integer). example: `cargo scaffold 7 [--year 2022] [--template grid]`"
+            );
             process::exit(1);
         }
     };
+    let (day, year) = (args.day, args.year);
 
-    let day_padded = format!("{day:02}");
-
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let template = match load_template(&args.template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to load template: {e}");
             process::exit(1);
         }
     };
+    let title = puzzle_title(year, day).unwrap_or_else(|| format!("Day {day}"));
+    let module = render(&template, day, year, &title, &args.answer);
+
+    let day_padded = format!("{day:02}");
+
+    let season_path = get_season_path(year);
+    let registry_path = season_path.join("mod.rs");
+    let input_path = get_path("inputs", year, day);
+    let example_path = get_path("examples", year, day);
+    let manifest_path = get_examples_dir(year, day).join(MANIFEST_FILE);
+    let module_path = season_path.join(format!("day{day_padded}.rs"));
+    let bin_path = PathBuf::from(format!("src/bin/{day_padded}.rs"));
+
+    if !registry_path.exists() {
+        match create_season(year) {
+            Ok(_) => {
+                println!("Created season {} in \"{}\"", year, season_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create season: {e}");
+                process::exit(1);
+            }
+        }
+    }
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    if args.update && module_path.exists() {
+        // The code of an existing day is kept, only missing tests are added
+        match add_tests(&module_path, &module, year, day) {
+            Ok(AddedTests::Module) => {
+                println!("Added tests to module file \"{}\"", module_path.display())
+            }
+            Ok(AddedTests::ExampleTests) => println!(
+                "Added `crate::example_tests!({year}, {day});` to the tests of module file \"{}\"",
+                module_path.display()
+            ),
+            Ok(AddedTests::Nothing) => println!("Kept module file \"{}\"", module_path.display()),
+            Err(e) => {
+                eprintln!("Failed to add tests to module: {e}");
+                process::exit(1);
+            }
+        }
+    } else {
+        let mut file = match safe_create_file(&module_path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create module file: {e}. Pass `--update` to complete an existing day.");
+                process::exit(1);
+            }
+        };
+
+        match file.write_all(module.as_bytes()) {
+            Ok(_) => {
+                println!("Created module file \"{}\"", module_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to write module contents: {e}");
+                process::exit(1);
+            }
+        }
+    }
+    // Day binaries are shared by every season, they pick the season from `--year`
+    if !bin_path.exists() {
+        let mut file = match safe_create_file(&bin_path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create binary file: {e}");
+                process::exit(1);
+            }
+        };
+
+        let bin = BIN_TEMPLATE.replace("DAY", &day.to_string());
+        match file.write_all(bin.as_bytes()) {
+            Ok(_) => {
+                println!("Created binary file \"{}\"", bin_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to write binary contents: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    match register(
+        &registry_path,
+        "days",
+        day.into(),
+        format!("    {day} => day{day_padded},"),
+    ) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Registered day {} in \"{}\"", day, registry_path.display());
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to register day: {e}");
             process::exit(1);
         }
     }
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -92,7 +390,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -100,9 +398,24 @@ fn main() {
         }
     }
 
+    if !manifest_path.exists() {
+        let created = create_file(&manifest_path)
+            .and_then(|mut file| file.write_all(MANIFEST_TEMPLATE.as_bytes()));
+        match created {
+            Ok(_) => {
+                println!("Created example manifest \"{}\"", manifest_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create example manifest: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if year == days::latest_year() {
+        println!("🎄 Type `cargo solve {day_padded}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day_padded} -- --year {year}` to run your solution.");
+    }
 }
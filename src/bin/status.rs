@@ -0,0 +1,50 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::args::parse_timeout;
+use advent_of_code::history;
+use advent_of_code::status::{self, DayStatus};
+use advent_of_code::{days, runner, ANSI_BOLD, ANSI_RESET};
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    year: u16,
+    timeout: Option<Duration>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::latest_year),
+        timeout: args
+            .opt_value_from_fn("--timeout", parse_timeout)?
+            .unwrap_or(Some(runner::DEFAULT_TIMEOUT)),
+    })
+}
+
+fn main() {
+    let Args { year, timeout } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+    let Some(season) = days::season(year) else {
+        eprintln!("No season registered for {year}.");
+        process::exit(1);
+    };
+
+    let days = status::collect(season, &history::load(history::HISTORY_PATH), timeout);
+    let stars: usize = days.iter().map(DayStatus::stars).sum();
+
+    println!("{ANSI_BOLD}🎄 Advent of Code {year}: {stars} ★{ANSI_RESET}");
+    println!();
+    println!("{}", status::render_calendar(&days));
+    println!();
+    println!("{}", status::render_table(&days));
+}
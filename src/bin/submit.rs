@@ -0,0 +1,124 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::{Client, Feedback};
+use advent_of_code::guesses::{self, Guess, GuessVerdict};
+use advent_of_code::helpers::try_read_input;
+use advent_of_code::{answers, days, runner};
+use std::process;
+use std::time::SystemTime;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        answer: args.opt_free_from_str()?,
+    })
+}
+
+/// Runs the solver of `part` of `day` on its input
+fn compute_answer(year: u16, day: u8, part: u8) -> Result<String, String> {
+    let solution = days::get(year, day).ok_or(format!("day {day} of {year} is not registered"))?;
+    let input = try_read_input(year, day).map_err(|e| format!("could not read input: {e}"))?;
+    let record = runner::run_part(solution, part, &input);
+
+    match (record.answer, record.error) {
+        (Some(answer), _) => Ok(answer),
+        (None, Some(error)) => Err(format!("solver failed: {error}")),
+        (None, None) => Err(String::from("part is not solved yet")),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if matches!(args.part, 1 | 2) => args,
+        Ok(args) => {
+            eprintln!("Invalid part {}, expected 1 or 2.", args.part);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+    let (day, part) = (args.day, args.part);
+    let year = args.year.unwrap_or_else(days::latest_year);
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => match compute_answer(year, day, part) {
+            Ok(answer) => {
+                println!("Computed answer: {answer}");
+                answer
+            }
+            Err(e) => {
+                eprintln!("Failed to compute the answer of day {day} part {part}: {e}");
+                process::exit(1);
+            }
+        },
+    };
+
+    if answers::read_answers(year, day)[usize::from(part) - 1].as_deref() == Some(answer.as_str()) {
+        println!("✅ {answer} is already the recorded answer of day {day} part {part}.");
+        return;
+    }
+
+    let guesses = guesses::read_guesses(year, day);
+    if let Some(guess) = guesses::ruled_out_by(&guesses, part, &answer) {
+        eprintln!("❌ Not submitting {answer}, it is ruled out by a previous guess: \"{guess}\".");
+        process::exit(1);
+    }
+
+    let deadline = guesses::read_deadline(year, day);
+    if let Some(wait) = deadline.and_then(|d| guesses::remaining_wait(d, SystemTime::now())) {
+        eprintln!(
+            "⏳ Not submitting {answer}, an answer was submitted too recently, wait {}s before trying again.",
+            wait.as_secs().max(1)
+        );
+        process::exit(1);
+    }
+
+    let feedback =
+        match Client::from_env().and_then(|client| client.submit(year, day, part, &answer)) {
+            Ok(feedback) => feedback,
+            Err(e) => {
+                eprintln!("Failed to submit the answer: {e}");
+                process::exit(1);
+            }
+        };
+    println!("{feedback}");
+
+    let saved = match GuessVerdict::from_feedback(&feedback) {
+        Some(verdict) => guesses::write_guess(
+            year,
+            day,
+            &Guess {
+                part,
+                answer: answer.clone(),
+                verdict,
+            },
+        ),
+        None if feedback == Feedback::Correct => answers::write_answer(year, day, part, &answer),
+        None => Ok(()),
+    }
+    .and_then(|()| match feedback.wait() {
+        Some(wait) => guesses::write_deadline(year, day, SystemTime::now() + wait),
+        None => Ok(()),
+    });
+    if let Err(e) = saved {
+        eprintln!("Failed to save the answer: {e}");
+    }
+    if feedback != Feedback::Correct {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,124 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::record::{self, Record, RECORD_FLAG};
+use advent_of_code::watch::{self, Snapshot};
+use advent_of_code::{days, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::path::PathBuf;
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+/// Delay between two checks of the watched files
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct Args {
+    day: u8,
+    year: u16,
+    release: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::latest_year),
+        release: args.contains(["-r", "--release"]),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Runs the tests of the module of the day. Returns whether they passed.
+fn run_tests(args: &Args) -> bool {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["test", "--quiet", "--lib"]);
+    if args.release {
+        cmd.arg("--release");
+    }
+    cmd.arg(format!("y{}::day{:02}::", args.year, args.day));
+
+    cmd.status().is_ok_and(|status| status.success())
+}
+
+/// Solves the day on its input, printing its usual output. Returns the `Records` of the run.
+fn run_solution(args: &Args) -> Vec<Record> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--quiet"]);
+    if args.release {
+        cmd.arg("--release");
+    }
+    cmd.args(["--bin", &format!("{:02}", args.day), "--"])
+        .args(["--year", &args.year.to_string(), RECORD_FLAG])
+        .stdout(Stdio::piped());
+
+    let output = match cmd.output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to spawn cargo: {e}");
+            return vec![];
+        }
+    };
+    let output = String::from_utf8_lossy(&output.stdout);
+    let (records, lines) = record::split_output(&output);
+    for line in lines {
+        println!("{line}");
+    }
+
+    records
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let mut snapshot = Snapshot::take(&watch::watched_paths(args.year, args.day));
+    let mut previous: Vec<Record> = vec![];
+    let mut changes: Vec<PathBuf> = vec![];
+
+    loop {
+        println!("----------");
+        if changes.is_empty() {
+            println!(
+                "{ANSI_BOLD}👀 Watching day {:02} of {}{ANSI_RESET}",
+                args.day, args.year
+            );
+        }
+        for path in &changes {
+            println!("{ANSI_ITALIC}Changed: {}{ANSI_RESET}", path.display());
+        }
+
+        println!("{ANSI_BOLD}🧪 Tests{ANSI_RESET}");
+        if run_tests(&args) {
+            println!("{ANSI_BOLD}🎄 Input{ANSI_RESET}");
+            let records = run_solution(&args);
+            if !records.is_empty() {
+                println!("{ANSI_BOLD}🔀 Answers{ANSI_RESET}");
+                for line in watch::diff_answers(&previous, &records) {
+                    println!("{line}");
+                }
+                previous = records;
+            }
+        } else {
+            println!("Tests failed, not running the input.");
+        }
+
+        // Waits for a change, then for the files to settle, e.g. while an editor saves
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = Snapshot::take(&watch::watched_paths(args.year, args.day));
+            changes = current.changes(&snapshot);
+            if !changes.is_empty() {
+                thread::sleep(POLL_INTERVAL);
+                snapshot = Snapshot::take(&watch::watched_paths(args.year, args.day));
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,452 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! A small client for the Advent of Code website.
+use crate::helpers::get_path;
+use crate::html;
+use std::fmt::Display;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, io};
+
+/// Environment variable holding the session cookie, takes precedence over `SESSION_FILE`
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// File in the home directory holding the session cookie
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Environment variable overriding `DEFAULT_BASE_URL`, e.g. to test against a local server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum ClientError {
+    SessionNotFound,
+    /// The server answered with an error status
+    BadStatus {
+        url: String,
+        status: u16,
+    },
+    /// The server could not be reached or its response could not be read
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or write it to ~/{SESSION_FILE}."
+            ),
+            ClientError::BadStatus { url, status } => match status {
+                400 | 500 => write!(
+                    f,
+                    "{url} answered with status {status}, the session cookie may have expired."
+                ),
+                404 => write!(
+                    f,
+                    "{url} was not found, the puzzle may not be unlocked yet."
+                ),
+                _ => write!(f, "{url} answered with status {status}."),
+            },
+            ClientError::Transport(e) => write!(f, "request failed: {e}"),
+            ClientError::Io(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => ClientError::BadStatus {
+                url: response.get_url().to_string(),
+                status,
+            },
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(error: io::Error) -> Self {
+        ClientError::Io(error)
+    }
+}
+
+/// Reads the session cookie from `SESSION_ENV`, or from `SESSION_FILE` in the home directory
+pub fn read_session() -> Result<String, ClientError> {
+    let from_env = env::var(SESSION_ENV).ok();
+    let from_file = || {
+        let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+        read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()
+    };
+
+    from_env
+        .or_else(from_file)
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(ClientError::SessionNotFound)
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Builds a `Client` for the base url in `BASE_URL_ENV`, or `DEFAULT_BASE_URL`, with the
+    /// session cookie found by `read_session`
+    pub fn from_env() -> Result<Self, ClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &read_session()?))
+    }
+
+    /// Fetches the puzzle input of `day`
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// Fetches the HTML page describing the puzzle of `day`
+    pub fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    /// Submits `answer` to `part` of `day` and returns the server's `Feedback`
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Feedback, ClientError> {
+        let part = part.to_string();
+        let response = self
+            .agent
+            .post(&format!("{}/{year}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part), ("answer", answer)])?;
+
+        let page = response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))?;
+        Ok(Feedback::parse(&html::to_markdown(&page)))
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()))
+    }
+}
+
+/// What the server said about a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    /// Too high, the next answer is accepted after the `Duration`
+    TooHigh(Duration),
+    /// Too low, the next answer is accepted after the `Duration`
+    TooLow(Duration),
+    /// Wrong, without telling whether the answer is too high or too low, the next answer is
+    /// accepted after the `Duration`
+    Wrong(Duration),
+    /// An answer was submitted too recently, the next one is accepted after the `Duration`
+    Wait(Duration),
+    /// The part is already solved, or not unlocked yet
+    WrongLevel,
+    /// Any other response, with its text
+    Unknown(String),
+}
+
+/// The wait assumed when the server imposes one without saying how long
+pub const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+impl Feedback {
+    /// Parses the text of the page returned after submitting an answer
+    pub fn parse(text: &str) -> Self {
+        if text.contains("That's the right answer") {
+            Feedback::Correct
+        } else if text.contains("your answer is too high") {
+            Feedback::TooHigh(parse_wait(text).unwrap_or(DEFAULT_WAIT))
+        } else if text.contains("your answer is too low") {
+            Feedback::TooLow(parse_wait(text).unwrap_or(DEFAULT_WAIT))
+        } else if text.contains("That's not the right answer") {
+            Feedback::Wrong(parse_wait(text).unwrap_or(DEFAULT_WAIT))
+        } else if text.contains("You gave an answer too recently") {
+            Feedback::Wait(parse_wait(text).unwrap_or(DEFAULT_WAIT))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Feedback::WrongLevel
+        } else {
+            Feedback::Unknown(text.trim().to_string())
+        }
+    }
+
+    /// The time to wait before submitting another answer, if any
+    pub fn wait(&self) -> Option<Duration> {
+        match self {
+            Feedback::TooHigh(wait)
+            | Feedback::TooLow(wait)
+            | Feedback::Wrong(wait)
+            | Feedback::Wait(wait) => Some(*wait),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "✅ That's the right answer!"),
+            Feedback::TooHigh(wait) => write!(
+                f,
+                "❌ That's not the right answer, it is too high. Wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Feedback::TooLow(wait) => write!(
+                f,
+                "❌ That's not the right answer, it is too low. Wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Feedback::Wrong(wait) => write!(
+                f,
+                "❌ That's not the right answer. Wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Feedback::Wait(duration) => write!(
+                f,
+                "⏳ An answer was submitted too recently, wait {}s before trying again.",
+                duration.as_secs()
+            ),
+            Feedback::WrongLevel => {
+                write!(f, "❔ This part is already solved, or not unlocked yet.")
+            }
+            Feedback::Unknown(text) => write!(f, "❔ Unexpected response:\n{text}"),
+        }
+    }
+}
+
+/// Parses the time to wait, either left after an answer submitted too recently, e.g. "You have
+/// 1m 5s left to wait.", or imposed by a wrong answer, e.g. "Please wait one minute before trying
+/// again."
+fn parse_wait(text: &str) -> Option<Duration> {
+    parse_time_left(text).or_else(|| parse_please_wait(text))
+}
+
+/// Parses e.g. "You have 1m 5s left to wait."
+fn parse_time_left(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("You have ")?;
+
+    time.split_whitespace()
+        .map(|token| {
+            let unit = token.trim_start_matches(|c: char| c.is_ascii_digit());
+            let value: u64 = token[..token.len() - unit.len()].parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Parses e.g. "please wait 5 minutes before trying again", with the amount in digits or words
+fn parse_please_wait(text: &str) -> Option<Duration> {
+    const NUMBERS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+
+    text.match_indices("wait ").find_map(|(start, _)| {
+        let mut tokens = text[start + 5..].split_whitespace();
+        let amount = tokens.next()?.to_ascii_lowercase();
+        let value = match amount.parse::<u64>() {
+            Ok(value) => value,
+            Err(_) => NUMBERS.iter().position(|n| *n == amount)? as u64 + 1,
+        };
+        let unit = tokens.next()?;
+        let seconds = if unit.starts_with("second") {
+            1
+        } else if unit.starts_with("minute") {
+            60
+        } else if unit.starts_with("hour") {
+            3600
+        } else {
+            return None;
+        };
+        Some(Duration::from_secs(value * seconds))
+    })
+}
+
+/// Downloads the input of `day` of `year` to "y{year}/inputs/{day}.txt", and its puzzle, converted to
+/// Markdown, to "y{year}/puzzles/{day}.md"
+pub fn download(client: &Client, year: u16, day: u8) -> Result<(), ClientError> {
+    let input_path = get_path("inputs", year, day);
+    let puzzle_path = get_path("puzzles", year, day).with_extension("md");
+
+    println!("Fetching puzzle for day {day}, {year}...");
+    let puzzle = html::to_markdown(&client.fetch_puzzle(year, day)?);
+    println!("Downloading input for day {day}, {year}...");
+    let input = client.fetch_input(year, day)?;
+
+    for path in [&puzzle_path, &input_path] {
+        if let Some(folder) = path.parent() {
+            create_dir_all(folder)?;
+        }
+    }
+    write(&puzzle_path, puzzle)?;
+    write(&input_path, input)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves one `(status, body)` response per request on a local port, and returns the
+    /// base url of the server along with a handle yielding the requests it received
+    pub(crate) fn serve(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                            content_length = length.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut content = vec![0; content_length];
+                    std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+                    request.push_str(&String::from_utf8(content).unwrap());
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = serve(vec![(200, "1000\n2000\n"), (404, "")]);
+        let client = Client::new(&base_url, "cookie");
+
+        assert_eq!(client.fetch_input(2022, 1).unwrap(), "1000\n2000\n");
+        assert!(matches!(
+            client.fetch_puzzle(2022, 25),
+            Err(ClientError::BadStatus { status: 404, .. })
+        ));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("session=cookie"));
+        assert!(requests[1].starts_with("GET /2022/day/25 HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let page = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+        let (base_url, server) = serve(vec![(200, page)]);
+        let client = Client::new(&base_url, "cookie");
+
+        assert_eq!(
+            client.submit(2022, 4, 2, "912").unwrap(),
+            Feedback::TooHigh(DEFAULT_WAIT)
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/4/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=912"));
+    }
+
+    #[test]
+    fn test_parse_feedback() {
+        assert_eq!(
+            Feedback::parse("That's the right answer! You are one gold star closer."),
+            Feedback::Correct
+        );
+        assert_eq!(
+            Feedback::parse("That's not the right answer; your answer is too low.  Please wait one minute before trying again."),
+            Feedback::TooLow(Duration::from_secs(60))
+        );
+        assert_eq!(
+            Feedback::parse("That's not the right answer; your answer is too high.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            Feedback::TooHigh(Duration::from_secs(300))
+        );
+        assert_eq!(
+            Feedback::parse("That's not the right answer. If you're stuck, [...]"),
+            Feedback::Wrong(DEFAULT_WAIT)
+        );
+        assert_eq!(
+            Feedback::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+            Feedback::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            Feedback::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again."),
+            Feedback::Wait(DEFAULT_WAIT)
+        );
+        assert_eq!(
+            Feedback::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Feedback::WrongLevel
+        );
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * This file contains template code.
+ * Seasons are registered in the `seasons!` invocation at the bottom of this file, and days in
+ * the `days!` invocation of their season, e.g. `src/y2022/mod.rs`.
+ * `cargo scaffold` does that for you.
+ */
+use crate::helpers::ParseError;
+use std::any::Any;
+use std::fmt::Display;
+
+/// A solution for one day of the calendar, runnable without knowing its answer types
+pub trait Day: Sync {
+    /// Year of the season the day belongs to
+    fn year(&self) -> u16;
+
+    /// Day of the calendar, from 1 to 25
+    fn day(&self) -> u8;
+
+    /// Solves the first part of the puzzle. Errors are rendered against `input`.
+    fn part_one(&self, input: &str) -> Result<Option<String>, String>;
+
+    /// Solves the second part of the puzzle. Errors are rendered against `input`.
+    fn part_two(&self, input: &str) -> Result<Option<String>, String>;
+
+    /// Alternative implementations of either part, compared by `cargo solve <day> -- --variants`
+    fn variants(&self) -> &'static [&'static dyn Variant] {
+        &[]
+    }
+}
+
+/// Name of the registered implementation of a part when compared to its `Variants`
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// A named alternative implementation of one part of a day, e.g. a naive one kept next to an
+/// optimized one
+pub trait Variant: Sync {
+    /// The part it solves, 1 or 2
+    fn part(&self) -> u8;
+
+    fn name(&self) -> &'static str;
+
+    /// Solves the part. Errors are rendered against `input`.
+    fn solve(&self, input: &str) -> Result<Option<String>, String>;
+}
+
+/// Value returned by a solver: an answer, `Option<answer>` while the part is not solved, or a
+/// `Result` of either for solvers that can fail
+pub trait Outcome {
+    /// Converts the value into the answer, or a message explaining why solving `input` failed
+    fn into_answer(self, input: &str) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> Outcome for Option<T> {
+    fn into_answer(self, _input: &str) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Outcome, E: Display + 'static> Outcome for Result<T, E> {
+    fn into_answer(self, input: &str) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => answer.into_answer(input),
+            Err(error) => Err(describe_error(&error, input)),
+        }
+    }
+}
+
+/// Implements `Outcome` for types that are always an answer
+macro_rules! answers {
+    ($($answer:ty),*) => {
+        $(impl Outcome for $answer {
+            fn into_answer(self, _input: &str) -> Result<Option<String>, String> {
+                Ok(Some(self.to_string()))
+            }
+        })*
+    };
+}
+
+answers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str);
+
+/// Describes `error`, with a pointer to the offending line of `input` for `ParseErrors`
+pub fn describe_error<E: Display + 'static>(error: &E, input: &str) -> String {
+    match (error as &dyn Any).downcast_ref::<ParseError>() {
+        Some(error) => error.render(input),
+        None => error.to_string(),
+    }
+}
+
+/// Adapts the `part_one` and `part_two` functions of a day module to the `Day` trait
+pub struct Solution<A, B> {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: fn(&str) -> A,
+    pub part_two: fn(&str) -> B,
+    pub variants: &'static [&'static dyn Variant],
+}
+
+impl<A: Outcome, B: Outcome> Day for Solution<A, B> {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn part_one(&self, input: &str) -> Result<Option<String>, String> {
+        (self.part_one)(input).into_answer(input)
+    }
+
+    fn part_two(&self, input: &str) -> Result<Option<String>, String> {
+        (self.part_two)(input).into_answer(input)
+    }
+
+    fn variants(&self) -> &'static [&'static dyn Variant] {
+        self.variants
+    }
+}
+
+/// Adapts a function of a day module to the `Variant` trait
+pub struct NamedSolver<A> {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: fn(&str) -> A,
+}
+
+impl<A: Outcome> Variant for NamedSolver<A> {
+    fn part(&self) -> u8 {
+        self.part
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn solve(&self, input: &str) -> Result<Option<String>, String> {
+        (self.solve)(input).into_answer(input)
+    }
+}
+
+/// The days solved for one year of Advent of Code
+pub struct Season {
+    pub year: u16,
+    /// Every registered `Day` of the season, ordered by day
+    pub days: &'static [&'static dyn Day],
+}
+
+/// Returns the registered `Season` of `year`, if any
+pub fn season(year: u16) -> Option<&'static Season> {
+    SEASONS.iter().find(|season| season.year == year)
+}
+
+/// Returns the most recent registered year, used when no year is given
+pub fn latest_year() -> u16 {
+    SEASONS
+        .iter()
+        .map(|season| season.year)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Returns the registered `Day` for `day` of `year`, if any
+pub fn get(year: u16, day: u8) -> Option<&'static dyn Day> {
+    season(year)?
+        .days
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
+
+/// Declares the day modules of a season and registers them into its `DAYS`. A day may list
+/// `Variants` of its parts on the same line: `15 => day15 { 1: [intervals => part_one_fast] },`
+macro_rules! days {
+    (year $year:literal; $($day:literal => $module:ident $({
+        $($part:literal: [$($name:ident => $function:ident),* $(,)?]),* $(,)?
+    })?,)*) => {
+        $(pub mod $module;)*
+
+        /// Every registered `Day` of the season, ordered by day
+        pub static DAYS: &[&dyn $crate::days::Day] = &[$(&$crate::days::Solution {
+            year: $year,
+            day: $day,
+            part_one: $module::part_one,
+            part_two: $module::part_two,
+            variants: &[$($($(&$crate::days::NamedSolver {
+                part: $part,
+                name: stringify!($name),
+                solve: $module::$function,
+            }),*),*)?],
+        }),*];
+    };
+}
+
+pub(crate) use days;
+
+/// Registers season modules into `SEASONS`
+macro_rules! seasons {
+    ($($year:literal => $module:ident,)*) => {
+        /// Every registered `Season`, ordered by year
+        pub static SEASONS: &[Season] = &[$(Season {
+            year: $year,
+            days: crate::$module::DAYS,
+        }),*];
+    };
+}
+
+seasons! {
+    2022 => y2022,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_ordered() {
+        let years: Vec<u16> = SEASONS.iter().map(|season| season.year).collect();
+        assert!(years.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for season in SEASONS {
+            let days: Vec<u8> = season.days.iter().map(|day| day.day()).collect();
+            assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(season.days.iter().all(|day| day.year() == season.year));
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(2022, 4).unwrap().day(), 4);
+        assert!(get(2022, 17).is_none());
+        assert!(get(1999, 4).is_none());
+        assert_eq!(latest_year(), 2022);
+        assert_eq!(
+            get(2022, 4).unwrap().part_one("2-4,6-8\n2-8,3-7"),
+            Ok(Some("1".into()))
+        );
+    }
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(Some(3).into_answer(""), Ok(Some("3".into())));
+        assert_eq!(None::<u64>.into_answer(""), Ok(None));
+        assert_eq!(Ok::<_, String>(3).into_answer(""), Ok(Some("3".into())));
+        assert_eq!(Ok::<_, String>(None::<u64>).into_answer(""), Ok(None));
+        assert_eq!(
+            Err::<u64, _>(String::from("oops")).into_answer(""),
+            Err("oops".into())
+        );
+        assert_eq!(
+            Err::<u64, _>(ParseError::new(2, 3, "oops")).into_answer("a\nbcd"),
+            Err("line 2, column 3: oops\n  |\n2 | bcd\n  |   ^".into())
+        );
+    }
+}
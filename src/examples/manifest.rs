@@ -0,0 +1,163 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ * It only depends on `std` because `build.rs` includes it too.
+ */
+//! Manifest of the answers expected for the named examples of a day.
+
+/// Name of the manifest in "y{year}/examples/{day}/"
+pub const MANIFEST_FILE: &str = "answers.txt";
+
+/// Answer expected for one part of a named example
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expected {
+    /// Name of the example, its file being "y{year}/examples/{day}/{example}.txt"
+    pub example: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// Whether `name` can name an example: it may only hold letters, digits, `_` and `-`, so that it
+/// is a single token of the manifest and a plain file name
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Parses a manifest: one `<example> <part> <answer>` line per expected answer, e.g.
+/// `larger 2 36`. Blank lines and lines starting with `#` are skipped. Multi-line answers are
+/// written on a single line with `\n` separators.
+pub fn parse_manifest(content: &str) -> Result<Vec<Expected>, String> {
+    let mut expected: Vec<Expected> = vec![];
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = |message: &str| format!("line {}: {message}", index + 1);
+        let mut tokens = line.splitn(3, char::is_whitespace);
+        let example = tokens.next().unwrap_or_default();
+        if !is_valid_name(example) {
+            return Err(error(
+                "example names may only hold letters, digits, '_' and '-'",
+            ));
+        }
+        let part = match tokens.next().map(str::parse) {
+            Some(Ok(part @ (1 | 2))) => part,
+            _ => return Err(error("expected part 1 or 2 after the example name")),
+        };
+        let answer = match tokens.next().map(str::trim) {
+            Some(answer) if !answer.is_empty() => answer.replace("\\n", "\n"),
+            _ => return Err(error("expected an answer after the part")),
+        };
+        // Names differing only by case or by `-` and `_` would generate the same test
+        let name = test_name(example, part);
+        if let Some(other) = expected
+            .iter()
+            .find(|other| other.part == part && test_name(&other.example, part) == name)
+        {
+            if other.example == example {
+                return Err(error("answer listed twice"));
+            }
+            return Err(error(&format!(
+                "\"{example}\" and \"{}\" would generate the same test, rename one of them",
+                other.example
+            )));
+        }
+
+        expected.push(Expected {
+            example: example.to_string(),
+            part,
+            answer,
+        });
+    }
+
+    Ok(expected)
+}
+
+/// Formats `expected` as a line of a manifest
+pub fn format_expected(expected: &Expected) -> String {
+    format!(
+        "{} {} {}",
+        expected.example,
+        expected.part,
+        expected.answer.replace('\n', "\\n")
+    )
+}
+
+/// Name of the test generated for `part` of `example`
+pub fn test_name(example: &str, part: u8) -> String {
+    format!(
+        "example_{}_part_{part}",
+        example.replace('-', "_").to_lowercase()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest =
+            "# example part answer\nsmall 1 13\n\nlarger-rope 2 36\nscreen 2 ##..\\n#..#\n";
+
+        assert_eq!(
+            parse_manifest(manifest),
+            Ok(vec![
+                Expected {
+                    example: String::from("small"),
+                    part: 1,
+                    answer: String::from("13"),
+                },
+                Expected {
+                    example: String::from("larger-rope"),
+                    part: 2,
+                    answer: String::from("36"),
+                },
+                Expected {
+                    example: String::from("screen"),
+                    part: 2,
+                    answer: String::from("##..\n#..#"),
+                },
+            ])
+        );
+        assert_eq!(test_name("larger-rope", 2), "example_larger_rope_part_2");
+
+        let screen = &parse_manifest(manifest).unwrap()[2];
+        assert_eq!(format_expected(screen), "screen 2 ##..\\n#..#");
+    }
+
+    #[test]
+    fn test_parse_manifest_invalid() {
+        assert!(parse_manifest("small 3 13")
+            .unwrap_err()
+            .starts_with("line 1:"));
+        assert!(parse_manifest("small 1").is_err());
+        assert!(parse_manifest("a.b 1 13").is_err());
+        assert_eq!(
+            parse_manifest("small 1 13\nsmall 1 14"),
+            Err(String::from("line 2: answer listed twice"))
+        );
+        assert_eq!(
+            parse_manifest("larger-rope 1 13\nlarger_rope 1 14"),
+            Err(String::from(
+                "line 2: \"larger_rope\" and \"larger-rope\" would generate the same test, rename one of them"
+            ))
+        );
+        assert!(parse_manifest("Small 1 13\nsmall 1 13").is_err());
+        assert!(parse_manifest("Small 1 13\nsmall 2 13").is_ok());
+    }
+
+    #[test]
+    fn test_is_valid_name() {
+        assert!(is_valid_name("larger-rope_2"));
+        assert!(!is_valid_name("my example"));
+        assert!(!is_valid_name("../x"));
+        assert!(!is_valid_name(""));
+    }
+}
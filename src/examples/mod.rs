@@ -0,0 +1,145 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Named examples of a day, stored as "y{year}/examples/{day}/{name}.txt" along with a manifest
+//! of their expected answers. `example_tests!` generates one test per expected answer.
+mod manifest;
+pub mod puzzle;
+
+pub use manifest::{
+    format_expected, is_valid_name, parse_manifest, test_name, Expected, MANIFEST_FILE,
+};
+
+use crate::days::{Day, Outcome};
+use crate::helpers::{get_examples_dir, read_named_example};
+use std::fs::{create_dir_all, read_dir, read_to_string, OpenOptions};
+use std::io::Write;
+
+/// Reads the manifest of the named examples of `day`
+pub fn read_manifest(year: u16, day: u8) -> Result<Vec<Expected>, String> {
+    let path = get_examples_dir(year, day).join(MANIFEST_FILE);
+    let content =
+        read_to_string(&path).map_err(|e| format!("could not read \"{}\": {e}", path.display()))?;
+
+    parse_manifest(&content).map_err(|e| format!("{}, {e}", path.display()))
+}
+
+/// Lists the names of the named examples of `day`, in alphabetical order, whether the manifest
+/// lists answers for them or not
+pub fn named_examples(year: u16, day: u8) -> Vec<String> {
+    let Ok(entries) = read_dir(get_examples_dir(year, day)) else {
+        return vec![];
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = path.file_stem()?.to_str()?.to_string();
+            let is_example = path.extension().is_some_and(|extension| extension == "txt")
+                && path.file_name().is_some_and(|file| file != MANIFEST_FILE);
+            (is_example && is_valid_name(&name)).then_some(name)
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Appends `expected` to the manifest of `day`, unless the manifest already lists an answer for
+/// that part of the example. Returns whether it was appended.
+pub fn add_expected(year: u16, day: u8, expected: &Expected) -> Result<bool, String> {
+    let folder = get_examples_dir(year, day);
+    let path = folder.join(MANIFEST_FILE);
+    let content = read_to_string(&path).unwrap_or_default();
+    let manifest = parse_manifest(&content).map_err(|e| format!("{}, {e}", path.display()))?;
+    if manifest
+        .iter()
+        .any(|other| other.example == expected.example && other.part == expected.part)
+    {
+        return Ok(false);
+    }
+
+    let write = || -> std::io::Result<()> {
+        create_dir_all(&folder)?;
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        if !content.is_empty() && !content.ends_with('\n') {
+            writeln!(file)?;
+        }
+        writeln!(file, "{}", format_expected(expected))
+    };
+    write().map_err(|e| format!("could not write \"{}\": {e}", path.display()))?;
+
+    Ok(true)
+}
+
+/// Number of expected answers of the named examples a day matches
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExampleResults {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+/// Runs `day` on its named examples, like the tests generated by `example_tests!` do. Fails if
+/// the manifest cannot be read.
+pub fn run_examples(day: &dyn Day) -> Result<ExampleResults, String> {
+    let (year, number) = (day.year(), day.day());
+    let mut results = ExampleResults::default();
+
+    for expected in read_manifest(year, number)? {
+        let path = get_examples_dir(year, number).join(format!("{}.txt", expected.example));
+        let answer = read_to_string(path)
+            .ok()
+            .and_then(|input| match expected.part {
+                1 => day.part_one(&input).ok().flatten(),
+                _ => day.part_two(&input).ok().flatten(),
+            });
+
+        if answer.as_deref() == Some(expected.answer.as_str()) {
+            results.passed += 1;
+        } else {
+            results.failed += 1;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Runs `solver` on `example` and asserts that it returns the answer the manifest expects for
+/// `part`. Called by the tests generated by `example_tests!`.
+pub fn check<T: Outcome>(year: u16, day: u8, example: &str, part: u8, solver: fn(&str) -> T) {
+    let manifest = read_manifest(year, day).unwrap_or_else(|e| panic!("{e}"));
+    let expected = manifest
+        .iter()
+        .find(|expected| expected.example == example && expected.part == part)
+        .unwrap_or_else(|| panic!("no answer expected for part {part} of example \"{example}\""));
+
+    let input = read_named_example(year, day, example);
+    let answer = solver(&input)
+        .into_answer(&input)
+        .unwrap_or_else(|e| panic!("solver failed on example \"{example}\": {e}"));
+
+    assert_eq!(
+        answer.as_deref(),
+        Some(expected.answer.as_str()),
+        "part {part} of example \"{example}\""
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_run_examples() {
+        let day = days::get(2022, 9).unwrap();
+        assert_eq!(
+            run_examples(day),
+            Ok(ExampleResults {
+                passed: 3,
+                failed: 0
+            })
+        );
+        assert!(run_examples(days::get(2022, 1).unwrap()).is_err());
+    }
+}
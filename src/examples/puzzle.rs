@@ -0,0 +1,153 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Extracts examples and their answers from the Markdown of a puzzle description.
+
+/// A fenced code block of a puzzle description
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodeBlock {
+    /// Line of the opening fence, starting at 1
+    pub line: usize,
+    pub text: String,
+}
+
+/// Emphasized code span of a puzzle description, usually the answer to an example
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Candidate {
+    /// Line of the span, starting at 1
+    pub line: usize,
+    /// Part of the puzzle the span belongs to: 2 once the "Part Two" heading is passed
+    pub part: u8,
+    pub answer: String,
+}
+
+/// Returns the fenced code blocks of `markdown`, in order
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut current: Option<CodeBlock> = None;
+
+    for (index, line) in markdown.lines().enumerate() {
+        if !line.trim_start().starts_with("```") {
+            if let Some(block) = &mut current {
+                block.text.push_str(line);
+                block.text.push('\n');
+            }
+            continue;
+        }
+
+        match current.take() {
+            Some(mut block) => {
+                // Downloaded puzzles end their blocks with a blank line
+                block.text.truncate(block.text.trim_end().len());
+                block.text.push('\n');
+                blocks.push(block);
+            }
+            None => {
+                current = Some(CodeBlock {
+                    line: index + 1,
+                    text: String::new(),
+                })
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Returns the emphasized code spans of `markdown`, e.g. `` `*13*` `` or ``*`13`*``, which the
+/// puzzle descriptions use for the answers to their examples
+pub fn answer_candidates(markdown: &str) -> Vec<Candidate> {
+    let mut candidates = vec![];
+    let mut part = 1;
+    let mut in_code = false;
+
+    for (index, line) in markdown.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        if line.contains("--- Part Two ---") {
+            part = 2;
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some((answer, after)) = next_emphasized_code(rest) {
+            candidates.push(Candidate {
+                line: index + 1,
+                part,
+                answer: answer.to_string(),
+            });
+            rest = after;
+        }
+    }
+
+    candidates
+}
+
+/// Finds the first emphasized code span of `text`. Returns its content and the text after it.
+fn next_emphasized_code(text: &str) -> Option<(&str, &str)> {
+    [("`*", "*`"), ("*`", "`*")]
+        .iter()
+        .filter_map(|(open, close)| {
+            let start = text.find(open)? + open.len();
+            let end = start + text[start..].find(close)?;
+            let answer = &text[start..end];
+            let is_span = !answer.contains(['`', '*']) && answer.trim() == answer;
+            (!answer.is_empty() && is_span).then_some((start, answer, &text[end + close.len()..]))
+        })
+        .min_by_key(|(start, _, _)| *start)
+        .map(|(_, answer, after)| (answer, after))
+}
+
+/// Returns the last candidate of `part` between the lines `from` and `to`, usually the answer to
+/// the example found at line `from` when the next example starts at line `to`
+pub fn answer_between(candidates: &[Candidate], part: u8, from: usize, to: usize) -> Option<&str> {
+    candidates
+        .iter()
+        .rfind(|candidate| candidate.part == part && (from..to).contains(&candidate.line))
+        .map(|candidate| candidate.answer.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "Consider:\n\n```\nR 4\nU 4\n\n```\n\nThere are `*13*` positions, not `13`.\n\n\\--- Part Two ---\n----------\n\nIt visits `*1*` position.\n\n```\nR 5\n`*2*`\n```\n\nNow it visits *`36`* positions, and *`4`* is *not* `*5`.\n";
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(
+            code_blocks(PUZZLE),
+            vec![
+                CodeBlock {
+                    line: 3,
+                    text: String::from("R 4\nU 4\n"),
+                },
+                CodeBlock {
+                    line: 16,
+                    text: String::from("R 5\n`*2*`\n"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_answer_candidates() {
+        let candidates = answer_candidates(PUZZLE);
+        let answers: Vec<(u8, &str)> = candidates
+            .iter()
+            .map(|candidate| (candidate.part, candidate.answer.as_str()))
+            .collect();
+
+        assert_eq!(answers, vec![(1, "13"), (2, "1"), (2, "36"), (2, "4")]);
+        assert_eq!(candidates[0].line, 9);
+        assert_eq!(answer_between(&candidates, 2, 3, 16), Some("1"));
+        assert_eq!(answer_between(&candidates, 2, 16, usize::MAX), Some("4"));
+        assert_eq!(answer_between(&candidates, 1, 16, usize::MAX), None);
+    }
+}
@@ -0,0 +1,201 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::Feedback;
+use crate::helpers::get_path;
+use std::fmt::Display;
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A wrong answer submitted for a part, as recorded in "y{year}/guesses/{day}.txt", which also
+/// records when the next answer is accepted after submitting one too early
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: GuessVerdict,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuessVerdict {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl GuessVerdict {
+    /// Returns the verdict matching a `Feedback` about a wrong answer
+    pub fn from_feedback(feedback: &Feedback) -> Option<Self> {
+        match feedback {
+            Feedback::TooHigh(_) => Some(GuessVerdict::TooHigh),
+            Feedback::TooLow(_) => Some(GuessVerdict::TooLow),
+            Feedback::Wrong(_) => Some(GuessVerdict::Wrong),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            GuessVerdict::TooHigh => "high",
+            GuessVerdict::TooLow => "low",
+            GuessVerdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl Guess {
+    /// Parses a line of a guesses file, e.g. `1 high 3265`
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut tokens = line.trim().splitn(3, ' ');
+        let part = tokens.next()?.parse().ok()?;
+        let verdict = match tokens.next()? {
+            "high" => GuessVerdict::TooHigh,
+            "low" => GuessVerdict::TooLow,
+            "wrong" => GuessVerdict::Wrong,
+            _ => return None,
+        };
+        let answer = tokens.next()?.to_string();
+
+        Some(Self {
+            part,
+            answer,
+            verdict,
+        })
+    }
+}
+
+impl Display for Guess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.part, self.verdict.as_str(), self.answer)
+    }
+}
+
+/// Reads the wrong answers submitted for `day`
+pub fn read_guesses(year: u16, day: u8) -> Vec<Guess> {
+    read_to_string(get_path("guesses", year, day))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(Guess::parse)
+        .collect()
+}
+
+/// Appends a wrong answer to "y{year}/guesses/{day}.txt"
+pub fn write_guess(year: u16, day: u8, guess: &Guess) -> std::io::Result<()> {
+    append_line(get_path("guesses", year, day), &guess.to_string())
+}
+
+/// Reads when the next answer of `day` is accepted, as recorded after a `Feedback` that imposes
+/// a wait
+pub fn read_deadline(year: u16, day: u8) -> Option<SystemTime> {
+    parse_deadline(&read_to_string(get_path("guesses", year, day)).unwrap_or_default())
+}
+
+/// Records in "y{year}/guesses/{day}.txt" that no answer is accepted before `deadline`, as a
+/// `wait <unix seconds>` line
+pub fn write_deadline(year: u16, day: u8, deadline: SystemTime) -> std::io::Result<()> {
+    let since_epoch = deadline.duration_since(UNIX_EPOCH).unwrap_or_default();
+    // Rounds up, so that the recorded deadline is never early
+    let seconds = since_epoch.as_secs() + u64::from(since_epoch.subsec_nanos() > 0);
+    append_line(get_path("guesses", year, day), &format!("wait {seconds}"))
+}
+
+/// Returns how long to wait from `now` until `deadline`, `None` when it has passed
+pub fn remaining_wait(deadline: SystemTime, now: SystemTime) -> Option<Duration> {
+    deadline
+        .duration_since(now)
+        .ok()
+        .filter(|wait| !wait.is_zero())
+}
+
+/// Returns the last deadline recorded in the content of a guesses file
+fn parse_deadline(content: &str) -> Option<SystemTime> {
+    content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("wait "))
+        .filter_map(|seconds| seconds.trim().parse().ok())
+        .next_back()
+        .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+fn append_line(path: PathBuf, line: &str) -> std::io::Result<()> {
+    if let Some(folder) = path.parent() {
+        create_dir_all(folder)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// Returns the previous guess of `part` that rules `answer` out, if any: the same answer, or a
+/// number above an answer too high or below an answer too low
+pub fn ruled_out_by<'a>(guesses: &'a [Guess], part: u8, answer: &str) -> Option<&'a Guess> {
+    let number = answer.trim().parse::<i128>().ok();
+
+    guesses
+        .iter()
+        .filter(|guess| guess.part == part)
+        .find(|guess| {
+            let bound = guess.answer.parse::<i128>().ok();
+            match (guess.verdict, number, bound) {
+                _ if guess.answer == answer.trim() => true,
+                (GuessVerdict::TooHigh, Some(number), Some(bound)) => number >= bound,
+                (GuessVerdict::TooLow, Some(number), Some(bound)) => number <= bound,
+                _ => false,
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_roundtrip() {
+        let guess = Guess {
+            part: 2,
+            answer: String::from("MCD"),
+            verdict: GuessVerdict::Wrong,
+        };
+
+        assert_eq!(guess.to_string(), "2 wrong MCD");
+        assert_eq!(Guess::parse(&guess.to_string()), Some(guess));
+        assert_eq!(Guess::parse("2 maybe 12"), None);
+    }
+
+    #[test]
+    fn test_ruled_out_by() {
+        let guesses: Vec<Guess> = ["1 high 500", "1 low 100", "1 wrong 250", "2 low 900"]
+            .iter()
+            .filter_map(|line| Guess::parse(line))
+            .collect();
+
+        assert_eq!(ruled_out_by(&guesses, 1, "600"), Some(&guesses[0]));
+        assert_eq!(ruled_out_by(&guesses, 1, "500"), Some(&guesses[0]));
+        assert_eq!(ruled_out_by(&guesses, 1, "42"), Some(&guesses[1]));
+        assert_eq!(ruled_out_by(&guesses, 1, "250"), Some(&guesses[2]));
+        assert_eq!(ruled_out_by(&guesses, 1, "300"), None);
+        assert_eq!(ruled_out_by(&guesses, 2, "600"), Some(&guesses[3]));
+        assert_eq!(ruled_out_by(&guesses, 2, "ABC"), None);
+    }
+
+    #[test]
+    fn test_deadline() {
+        let content = "1 high 500\nwait 1000\n1 low 100\nwait 2000\n";
+        let deadline = parse_deadline(content).unwrap();
+
+        assert_eq!(deadline, UNIX_EPOCH + Duration::from_secs(2000));
+        assert_eq!(parse_deadline("1 high 500\n"), None);
+        assert_eq!(Guess::parse("wait 2000"), None);
+
+        let now = UNIX_EPOCH + Duration::from_secs(1940);
+        assert_eq!(remaining_wait(deadline, now), Some(Duration::from_secs(60)));
+        assert_eq!(remaining_wait(deadline, deadline), None);
+        assert_eq!(
+            remaining_wait(deadline, now + Duration::from_secs(61)),
+            None
+        );
+    }
+}
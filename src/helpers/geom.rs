@@ -0,0 +1,453 @@
+//! Points and directions on an unbounded plane or in space. As in `grid`, `y` grows downwards:
+//! `Up` is towards negative `y`.
+use super::ParseError;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Signed integer usable as the coordinate of a point
+pub trait Coord:
+    Copy
+    + Debug
+    + Default
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    /// Converts a unit offset, e.g. the `dx` of a direction
+    fn from_offset(offset: isize) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+
+            fn from_offset(offset: isize) -> Self {
+                offset as $t
+            }
+        }
+    )*};
+}
+
+coord!(i8, i16, i32, i64, i128, isize);
+
+/// Point, or vector, of the plane
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Point, or vector, of space
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Implements the operators of a point type, coordinate by coordinate
+macro_rules! point_ops {
+    ($point:ident { $($c:ident),+ }) => {
+        impl<T: Coord> Add for $point<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Sub for $point<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Neg for $point<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $point<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: Coord> Div<T> for $point<T> {
+            type Output = Self;
+            fn div(self, rhs: T) -> Self {
+                Self { $($c: self.$c / rhs),+ }
+            }
+        }
+
+        impl<T: Coord> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Coord> MulAssign<T> for $point<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: Coord> $point<T> {
+            /// Sum of the absolute coordinates of `self - other`
+            pub fn manhattan(self, other: Self) -> T {
+                let delta = self - other;
+                T::ZERO $(+ delta.$c.abs())+
+            }
+
+            /// Largest absolute coordinate of `self - other`, i.e. the number of king moves
+            pub fn chebyshev(self, other: Self) -> T {
+                let delta = self - other;
+                [$(delta.$c.abs()),+].into_iter().max().unwrap()
+            }
+
+            /// The vector with the sign of each coordinate, e.g. `(3, -2)` gives `(1, -1)`
+            pub fn signum(self) -> Self {
+                Self { $($c: self.$c.signum()),+ }
+            }
+
+            /// Moves one step towards `target`, diagonally when both coordinates differ, as a
+            /// rope tail follows its head
+            pub fn step_towards(self, target: Self) -> Self {
+                self + (target - self).signum()
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+impl<T: Coord> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves one step in `direction`
+    pub fn step(self, direction: impl Into<Dir8>) -> Self {
+        let (dx, dy) = direction.into().offset();
+        Self::new(self.x + T::from_offset(dx), self.y + T::from_offset(dy))
+    }
+
+    /// The four points sharing a side with `self`, clockwise from the one above
+    pub fn neighbours4(self) -> [Self; 4] {
+        Dir4::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The eight points sharing a side or a corner with `self`, clockwise from the one above
+    pub fn neighbours8(self) -> [Self; 8] {
+        Dir8::ALL.map(|direction| self.step(direction))
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The six points sharing a face with `self`
+    pub fn neighbours6(self) -> [Self; 6] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Self::new(-one, zero, zero),
+            Self::new(one, zero, zero),
+            Self::new(zero, -one, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, zero, -one),
+            Self::new(zero, zero, one),
+        ]
+        .map(|delta| self + delta)
+    }
+
+    /// The 26 points sharing a face, an edge or a corner with `self`
+    pub fn neighbours26(self) -> Vec<Self> {
+        let units = [-T::ONE, T::ZERO, T::ONE];
+        units
+            .iter()
+            .flat_map(|&x| units.iter().flat_map(move |&y| units.map(|z| (x, y, z))))
+            .filter(|&delta| delta != (T::ZERO, T::ZERO, T::ZERO))
+            .map(|(x, y, z)| self + Self::new(x, y, z))
+            .collect()
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// One of the four directions along the axes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All four directions, clockwise from `Up`
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Parses `U`, `D`, `L` and `R`, or the arrows `^`, `v`, `<` and `>`
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Self::Up),
+            'D' | 'v' => Some(Self::Down),
+            'L' | '<' => Some(Self::Left),
+            'R' | '>' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    /// Returns the `(dx, dy)` of one step in the direction
+    pub fn offset(self) -> (isize, isize) {
+        Dir8::from(self).offset()
+    }
+
+    /// The direction a quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction a quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// The opposite direction
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ParseError;
+
+    /// Parses a single letter or arrow, see `from_char`. Errors are located on line 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Self::from_char), chars.next()) {
+            (Some(direction), None) => Ok(direction),
+            _ => Err(ParseError::at_line(
+                1,
+                format!("unexpected direction '{s}'"),
+            )),
+        }
+    }
+}
+
+/// One of the eight directions, diagonals included
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// The four directions along the axes, clockwise from `Up`
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// All eight directions, clockwise from `Up`
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Returns the `(dx, dy)` of one step in the direction
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+            Self::UpLeft => (-1, -1),
+            Self::UpRight => (1, -1),
+            Self::DownLeft => (-1, 1),
+            Self::DownRight => (1, 1),
+        }
+    }
+
+    /// The direction an eighth of a turn clockwise
+    pub fn clockwise(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn counterclockwise
+    pub fn counterclockwise(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// The direction a quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 2) % 8]
+    }
+
+    /// The direction a quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 6) % 8]
+    }
+
+    /// The opposite direction
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(direction: Dir4) -> Self {
+        Self::ORTHOGONAL[direction as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        let mut a = Point2::new(3, -2);
+        let b = Point2::new(1, 4);
+
+        assert_eq!(a + b, Point2::new(4, 2));
+        assert_eq!(a - b, Point2::new(2, -6));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!(a * 2, Point2::new(6, -4));
+        a += b;
+        a -= Point2::new(0, 1);
+        assert_eq!(a, Point2::new(4, 1));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point2::new(8, 7), Point2::new(2, 10));
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 6);
+
+        let (a, b) = (Point3::new(1, 1, 1), Point3::new(2, -1, 5));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+
+    #[test]
+    fn test_step_towards() {
+        let tail = Point2::new(0, 0);
+        assert_eq!(tail.step_towards(Point2::new(2, 1)), Point2::new(1, 1));
+        assert_eq!(tail.step_towards(Point2::new(0, -2)), Point2::new(0, -1));
+        assert_eq!(tail.step_towards(tail), tail);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point2::new(0, 0);
+        assert_eq!(
+            point.neighbours4(),
+            [(0, -1), (1, 0), (0, 1), (-1, 0)].map(Point2::from)
+        );
+        assert_eq!(point.neighbours8().len(), 8);
+        assert!(point
+            .neighbours8()
+            .iter()
+            .all(|neighbour| point.chebyshev(*neighbour) == 1));
+
+        let point = Point3::new(1, 1, 1);
+        assert!(point
+            .neighbours6()
+            .iter()
+            .all(|neighbour| point.manhattan(*neighbour) == 1));
+        assert_eq!(point.neighbours26().len(), 26);
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+        assert_eq!(Dir8::Up.clockwise(), Dir8::UpRight);
+        assert_eq!(Dir8::Up.counterclockwise(), Dir8::UpLeft);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::UpRight);
+        assert_eq!(Dir8::DownRight.reverse(), Dir8::UpLeft);
+        assert_eq!(Dir8::from(Dir4::Left), Dir8::Left);
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!("U".parse::<Dir4>(), Ok(Dir4::Up));
+        assert_eq!(">".parse::<Dir4>(), Ok(Dir4::Right));
+        assert_eq!(Dir4::from_char('v'), Some(Dir4::Down));
+        assert_eq!(Dir4::from_char('x'), None);
+        assert_eq!(
+            "UP".parse::<Dir4>(),
+            Err(ParseError::at_line(1, "unexpected direction 'UP'"))
+        );
+    }
+}
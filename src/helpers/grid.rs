@@ -0,0 +1,456 @@
+//! Two-dimensional maps: `Grid` for the rectangular maps of most puzzles, and `SparseGrid` for
+//! unbounded ones.
+use super::geom::Point2;
+use super::ParseError;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// Position in a `Grid`: column `x` from the left, then row `y` from the top
+pub type Pos = (usize, usize);
+
+/// One of the eight directions of a `Grid`, `Up` being towards the first row
+pub use super::geom::Dir8 as Direction;
+
+/// A rectangular map of cells, stored row after row
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a `width` x `height` grid, the cell at each position given by `cell`
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a map of `char`s, one row per line, into cells with `cell`. Rows must all have the
+    /// same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Self::try_parse(input, |c| Some(cell(c)))
+    }
+
+    /// Same as `parse`, for maps in which `cell` rejects some `char`s by returning `None`
+    pub fn try_parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = input.trim_end_matches('\n').lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            let length = line.chars().count();
+            if length != width {
+                let message = format!("expected {width} cells, found {length}");
+                return Err(ParseError::new(index + 1, length.min(width) + 1, message));
+            }
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::new(index + 1, column + 1, format!("unexpected {c:?}"))
+                })?;
+                cells.push(value);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pos` is on the grid
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.1 * self.width + pos.0])
+    }
+
+    /// Replaces the cell at `pos`, returning the previous one, or `None` when `pos` is not on
+    /// the grid
+    pub fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Returns the position one step away from `pos` in `direction`, if it is on the grid
+    pub fn step(&self, (x, y): Pos, direction: impl Into<Direction>) -> Option<Pos> {
+        let (dx, dy) = direction.into().offset();
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Positions of the up to four cells sharing a side with `pos`
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Positions of the up to eight cells sharing a side or a corner with `pos`
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Walks away from `pos` in `direction` until the edge, `pos` excluded
+    pub fn ray(
+        &self,
+        pos: Pos,
+        direction: impl Into<Direction>,
+    ) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        let direction = direction.into();
+        std::iter::successors(self.step(pos, direction), move |pos| {
+            self.step(*pos, direction)
+        })
+        .map(|pos| (pos, &self[pos]))
+    }
+
+    /// Cells of row `y`, from left to right. Empty when the row is not on the grid.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> + '_ {
+        let start = (y.min(self.height)) * self.width;
+        let end = if y < self.height {
+            start + self.width
+        } else {
+            start
+        };
+        self.cells[start..end].iter()
+    }
+
+    /// Cells of column `x`, from top to bottom. Empty when the column is not on the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// Every position of the grid, row after row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell of the grid with its position, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row after row, matching `predicate`
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Positions of all the cells matching `predicate`, row after row
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Builds a grid of the same size from the cells of this one
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a `width` x `height` grid filled with `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swaps rows and columns
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid a quarter turn clockwise: the first row becomes the last column
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise: the first row becomes the first column
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// Returns the cell at `pos`. Panics when `pos` is not on the grid, see `get`.
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is not on the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is not on the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    /// Renders the cells row after row, one line per row
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An unbounded map holding only the cells that were set, e.g. the cave of 2022 day 14. Its
+/// positions are `Point2`s, which may be negative and move with `Point2::step` and neighbours.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point2) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point2) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets the cell at `point`, returning the previous one, if any
+    pub fn insert(&mut self, point: Point2, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point2) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// The cells that were set with their position, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> + '_ {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    /// Returns the top-left and bottom-right corners of the smallest rectangle holding every cell
+    /// that was set, `None` when there is none
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        self.cells.keys().fold(None, |bounds, &point| match bounds {
+            None => Some((point, point)),
+            Some((min, max)) => Some((
+                Point2::new(min.x.min(point.x), min.y.min(point.y)),
+                Point2::new(max.x.max(point.x), max.y.max(point.y)),
+            )),
+        })
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for SparseGrid<T> {
+    /// Renders the cells within `bounds`, `.` standing for the cells that were not set
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.get(Point2::new(x, y)) {
+                    Some(cell) => write!(f, "{cell}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n", |c| c.to_digit(10).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert_eq!(
+            Grid::parse("123\n45", |c| c).unwrap_err(),
+            ParseError::new(2, 3, "expected 3 cells, found 2")
+        );
+        assert_eq!(
+            Grid::try_parse("12\n3x", |c| c.to_digit(10)).unwrap_err(),
+            ParseError::new(2, 2, "unexpected 'x'")
+        );
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = digits();
+
+        assert_eq!(grid.get((1, 1)), Some(&5));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.set((0, 0), 9), Some(1));
+        assert_eq!(grid.set((0, 2), 9), None);
+        assert_eq!(grid[(0, 0)], 9);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits();
+
+        let mut around: Vec<Pos> = grid.neighbours4((0, 0)).collect();
+        around.sort();
+        assert_eq!(around, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = digits();
+
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(
+            grid.ray((2, 1), Direction::Left).collect::<Vec<_>>(),
+            vec![((1, 1), &5), ((0, 1), &4)]
+        );
+        assert_eq!(
+            grid.ray((0, 1), Direction::UpRight).collect::<Vec<_>>(),
+            vec![((1, 0), &2)]
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = digits();
+
+        assert_eq!(grid.find(|&cell| cell > 3), Some((0, 1)));
+        assert_eq!(grid.find(|&cell| cell > 9), None);
+        assert_eq!(
+            grid.find_all(|cell| cell % 2 == 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = digits();
+
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "36\n25\n14");
+        assert_eq!(grid.map(|cell| cell * 2)[(2, 1)], 12);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = [(Point2::new(500, 0), '+'), (Point2::new(498, 2), '#')]
+            .into_iter()
+            .collect();
+        let sand = Point2::new(499, 3);
+        grid.insert(sand, 'o');
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(
+            grid.bounds(),
+            Some((Point2::new(498, 0), Point2::new(500, 3)))
+        );
+        assert_eq!(grid.to_string(), "..+\n...\n#..\n.o.");
+        assert_eq!(grid.remove(sand), Some('o'));
+        assert!(!grid.contains(sand));
+        assert_eq!(SparseGrid::<char>::new().to_string(), "");
+    }
+}
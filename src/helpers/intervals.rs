@@ -0,0 +1,247 @@
+//! Sets of integers stored as sorted, disjoint ranges, for puzzles about intervals.
+use std::fmt::Debug;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// Integer that can bound the ranges of a `RangeSet`
+pub trait Bound: Copy + Debug + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! bound {
+    ($($t:ty),*) => {$(
+        impl Bound for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        }
+    )*};
+}
+
+bound!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Set of integers, stored as the sorted ranges it covers. Ranges that overlap or touch are
+/// merged, so two ranges of the set are always separated by a gap.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Bound> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// The ranges of the set, in increasing order
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Adds the values of `range`, merging it with the ranges it overlaps or touches
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merged = if first < last {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Adds the values of `range`, its end included
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        self.insert(start..end + T::ONE);
+    }
+
+    /// Removes the values of `range`, splitting the range of the set it falls into if needed
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+
+        let before = self.ranges[first].start..range.start;
+        let after = range.end..self.ranges[last - 1].end;
+        let kept = [before, after].into_iter().filter(|r| !r.is_empty());
+        self.ranges.splice(first..last, kept);
+    }
+
+    /// Values in `self` or in `other`
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    /// Values in both `self` and `other`
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Values in `self` but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |len, range| len + (range.end - range.start))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Whether `value` is in the set
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    /// Whether every value of `range` is in the set
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    /// Whether some value of `range` is in the set
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start < range.end && !range.is_empty())
+    }
+
+    /// The ranges of `within` that are not in the set, in increasing order
+    pub fn gaps(&self, within: Range<T>) -> impl Iterator<Item = Range<T>> {
+        RangeSet::from_iter([within])
+            .difference(self)
+            .ranges
+            .into_iter()
+    }
+}
+
+impl<T: Bound> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T: Bound> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert_inclusive(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = RangeSet::new();
+        set.insert(10..12);
+        set.insert(1..3);
+        set.insert(5..7);
+        assert_eq!(set.ranges(), [1..3, 5..7, 10..12]);
+
+        // Touching ranges are merged
+        set.insert(3..4);
+        assert_eq!(set.ranges(), [1..4, 5..7, 10..12]);
+
+        set.insert(6..11);
+        assert_eq!(set.ranges(), [1..4, 5..12]);
+
+        set.insert_inclusive(0..=20);
+        assert_eq!(set, RangeSet::from_iter([0..=20]));
+        set.insert(4..4);
+        assert_eq!(set, RangeSet::from_iter([0..=20]));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = RangeSet::from_iter([0..10, 20..30]);
+        set.remove(3..5);
+        assert_eq!(set.ranges(), [0..3, 5..10, 20..30]);
+
+        set.remove(8..25);
+        assert_eq!(set.ranges(), [0..3, 5..8, 25..30]);
+
+        set.remove(-5..6);
+        assert_eq!(set.ranges(), [6..8, 25..30]);
+
+        set.remove(10..20);
+        assert_eq!(set.ranges(), [6..8, 25..30]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = RangeSet::from_iter([0..5, 10..15]);
+        let b = RangeSet::from_iter([3..12, 20..25]);
+
+        assert_eq!(a.union(&b).ranges(), [0..15, 20..25]);
+        assert_eq!(a.intersection(&b).ranges(), [3..5, 10..12]);
+        assert_eq!(a.difference(&b).ranges(), [0..3, 12..15]);
+        assert_eq!(b.difference(&a).ranges(), [5..10, 20..25]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = RangeSet::from_iter([2..=4, 6..=8]);
+
+        assert_eq!(set.len(), 6);
+        assert!(set.contains(4));
+        assert!(!set.contains(5));
+        assert!(set.contains_range(&(6..9)));
+        assert!(!set.contains_range(&(3..7)));
+        assert!(set.overlaps(&(4..6)));
+        assert!(!set.overlaps(&(5..6)));
+        assert_eq!(set.gaps(0..10).collect::<Vec<_>>(), [0..2, 5..6, 9..10]);
+        assert!(RangeSet::<u32>::new().is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+pub mod geom;
+pub mod grid;
+pub mod intervals;
+pub mod parse;
+pub mod search;
+
+use std::env::current_dir;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::read_to_string as read_file;
+use std::path::PathBuf;
+
+/// Returns the `String` from file "y{year}/inputs/{day}.txt". Panics with the path when the file
+/// cannot be read, see `try_read_input` to handle that case.
+pub fn read_input(year: u16, day: u8) -> String {
+    let input_path: PathBuf = get_input_path(year, day);
+    read_file(&input_path)
+        .unwrap_or_else(|e| panic!("could not read \"{}\": {e}", input_path.display()))
+}
+
+/// Returns the content of file "y{year}/inputs/{day}.txt", or the error raised while reading it
+pub fn try_read_input(year: u16, day: u8) -> std::io::Result<String> {
+    read_file(get_input_path(year, day))
+}
+
+/// Returns the `String` from file "y{year}/examples/{day}.txt"
+pub fn read_example(year: u16, day: u8) -> String {
+    let input_path = get_examples_path(year, day);
+    let s = read_file(input_path);
+    s.unwrap()
+}
+
+/// Returns the `String` from file "y{year}/examples/{day}/{name}.txt"
+pub fn read_named_example(year: u16, day: u8, name: &str) -> String {
+    let path = get_examples_dir(year, day).join(format!("{name}.txt"));
+    read_file(&path).unwrap_or_else(|e| panic!("could not read \"{}\": {e}", path.display()))
+}
+
+/// Returns the content of file "y{year}/puzzles/{day}.md", or the error raised while reading it
+pub fn try_read_puzzle(year: u16, day: u8) -> std::io::Result<String> {
+    read_file(get_path("puzzles", year, day).with_extension("md"))
+}
+
+/// Gets the path for a file in "examples" folder
+fn get_examples_path(year: u16, day: u8) -> PathBuf {
+    get_path("examples", year, day)
+}
+
+/// Gets the path of the folder holding the named examples of a day, e.g. "y2022/examples/09"
+pub fn get_examples_dir(year: u16, day: u8) -> PathBuf {
+    get_season_path(year)
+        .join("examples")
+        .join(format!("{day:02}"))
+}
+
+/// Gets the path for a file in "inputs" folder
+fn get_input_path(year: u16, day: u8) -> PathBuf {
+    get_path("inputs", year, day)
+}
+
+/// Gets the path for a file in one of the txt folders of a season
+pub fn get_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    get_season_path(year)
+        .join(folder)
+        .join(format!("{day:02}.txt"))
+}
+
+/// Gets the path of the folder holding the code and files of a season, e.g. "src/y2022"
+pub fn get_season_path(year: u16) -> PathBuf {
+    let cwd = current_dir().unwrap();
+    cwd.join("src").join(format!("y{year}"))
+}
+
+/// Error raised while parsing a puzzle input, located by its line and column, both starting at 1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Builds a `ParseError` pointing to the start of `line`
+    pub fn at_line(line: usize, message: impl Into<String>) -> Self {
+        Self::new(line, 1, message)
+    }
+
+    /// Builds a `ParseError` pointing to `token`, which must be a slice of `line`
+    pub fn at_token(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|offset| *offset <= text.len())
+            .unwrap_or_default();
+
+        Self::new(line, text[..offset].chars().count() + 1, message)
+    }
+
+    /// Moves the error down by `lines`, for errors raised while parsing a block that does not
+    /// start at the first line of the input
+    pub fn offset_lines(self, lines: usize) -> Self {
+        Self {
+            line: self.line + lines,
+            ..self
+        }
+    }
+
+    /// Renders the error, followed by the offending line of `input` and a pointer to its column
+    pub fn render(&self, input: &str) -> String {
+        let Some(line) = input.lines().nth(self.line.saturating_sub(1)) else {
+            return self.to_string();
+        };
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let padding = " ".repeat(self.column.saturating_sub(1));
+
+        format!("{self}\n{margin} |\n{number} | {line}\n{margin} | {padding}^")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_at_token() {
+        let line = "move 1 from x to 2";
+        let error = ParseError::at_token(3, line, &line[12..13], "invalid stack");
+
+        assert_eq!(error, ParseError::new(3, 13, "invalid stack"));
+        assert_eq!(error.offset_lines(2).line, 5);
+    }
+
+    #[test]
+    fn test_parse_error_render() {
+        let input = "noop\naddx 3\naddx x\nnoop";
+        let error = ParseError::new(3, 6, "invalid number");
+
+        assert_eq!(
+            error.render(input),
+            "line 3, column 6: invalid number\n  |\n3 | addx x\n  |      ^"
+        );
+        assert_eq!(
+            ParseError::at_line(9, "missing line").render(input),
+            "line 9, column 1: missing line"
+        );
+    }
+}
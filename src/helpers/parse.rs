@@ -0,0 +1,255 @@
+//! Building blocks for parsing puzzle inputs. Functions working on a single line or block locate
+//! their errors on line 1, move them with `ParseError::offset_lines`, or use `lines` and `blocks`
+//! which do it for you.
+use super::ParseError;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parses every line of `input` with `parse_line`, moving its errors to the line they come from
+pub fn lines<T>(
+    input: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|e| e.offset_lines(index)))
+        .collect()
+}
+
+/// Iterates over the blocks of lines separated by blank lines, along with the number of lines
+/// before each block, to pass to `ParseError::offset_lines`
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut lines = input.split_inclusive('\n').enumerate().peekable();
+    let mut offset = 0;
+
+    std::iter::from_fn(move || {
+        // Skips the blank lines before the block
+        while let Some((_, line)) = lines.next_if(|(_, line)| line.trim().is_empty()) {
+            offset += line.len();
+        }
+        let (first_line, _) = *lines.peek()?;
+
+        let start = offset;
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            offset += line.len();
+        }
+        let block = input[start..offset].trim_end_matches(['\n', '\r']);
+
+        Some((first_line, block))
+    })
+}
+
+/// Extracts the integers of `line`, each with its leading `-` if any. A `-` between two digits is
+/// a separator, so `2-4` gives `2` and `4`.
+pub fn integers(line: &str) -> Result<Vec<i64>, ParseError> {
+    let bytes = line.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let token = &line[start..i];
+        let number = token
+            .parse()
+            .map_err(|e| ParseError::at_token(1, line, token, format!("{e}")))?;
+        numbers.push(number);
+    }
+
+    Ok(numbers)
+}
+
+/// Extracts exactly `N` integers from `line`, see `integers`
+pub fn ints<const N: usize>(line: &str) -> Result<[i64; N], ParseError> {
+    let numbers = integers(line)?;
+    let count = numbers.len();
+
+    numbers
+        .try_into()
+        .map_err(|_| ParseError::at_line(1, format!("expected {N} integers, found {count}")))
+}
+
+/// Matches `line` against `pattern`, where each `{}` stands for a field, and parses the fields
+/// into a tuple. Fields must be separated by some text of the pattern. Leading whitespace of
+/// `line` is skipped.
+pub fn scan<T: Fields>(pattern: &str, line: &str) -> Result<T, ParseError> {
+    let fields = split_fields(pattern, line, T::COUNT)?;
+    T::parse_fields(line, &fields)
+}
+
+/// Same as `scan`, returning the `N` fields as they appear in `line`
+pub fn scan_fields<'a, const N: usize>(
+    pattern: &str,
+    line: &'a str,
+) -> Result<[&'a str; N], ParseError> {
+    let fields = split_fields(pattern, line, N)?;
+    Ok(fields.try_into().unwrap())
+}
+
+/// Cuts `line` into the `count` fields of `pattern`
+fn split_fields<'a>(
+    pattern: &str,
+    line: &'a str,
+    count: usize,
+) -> Result<Vec<&'a str>, ParseError> {
+    let mut literals = pattern.split("{}");
+    let prefix = literals.next().unwrap_or_default();
+    let literals: Vec<&str> = literals.collect();
+    assert_eq!(
+        literals.len(),
+        count,
+        "\"{pattern}\" does not have {count} fields"
+    );
+
+    let mut rest = line
+        .trim_start()
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at_line(1, format!("expected \"{pattern}\"")))?;
+    let mut fields = Vec::with_capacity(count);
+    for (k, literal) in literals.iter().enumerate() {
+        let last = k == literals.len() - 1;
+        let end = match (literal.is_empty(), last) {
+            (true, true) => Some(rest.len()),
+            (true, false) => panic!("fields of \"{pattern}\" must be separated by some text"),
+            (false, true) => rest.ends_with(literal).then(|| rest.len() - literal.len()),
+            (false, false) => rest.find(literal),
+        };
+        let end = end.ok_or_else(|| {
+            ParseError::at_token(1, line, rest, format!("expected \"{}\"", literal.trim()))
+        })?;
+
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    Ok(fields)
+}
+
+/// Tuple of values that `scan` can parse from the fields of a line
+pub trait Fields: Sized {
+    const COUNT: usize;
+
+    /// Parses `fields`, which are slices of `line`
+    fn parse_fields(line: &str, fields: &[&str]) -> Result<Self, ParseError>;
+}
+
+/// Parses a field of `line` into a `T`, locating the error on the field
+fn field<T: FromStr>(line: &str, field: &str) -> Result<T, ParseError>
+where
+    T::Err: Display,
+{
+    field
+        .parse()
+        .map_err(|e| ParseError::at_token(1, line, field, format!("{e}")))
+}
+
+macro_rules! fields {
+    ($count:literal: $($t:ident $k:tt),+) => {
+        impl<$($t: FromStr),+> Fields for ($($t,)+)
+        where
+            $($t::Err: Display),+
+        {
+            const COUNT: usize = $count;
+
+            fn parse_fields(line: &str, fields: &[&str]) -> Result<Self, ParseError> {
+                Ok(($(field::<$t>(line, fields[$k])?,)+))
+            }
+        }
+    };
+}
+
+fields!(1: A 0);
+fields!(2: A 0, B 1);
+fields!(3: A 0, B 1, C 2);
+fields!(4: A 0, B 1, C 2, D 3);
+fields!(5: A 0, B 1, C 2, D 3, E 4);
+fields!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let parsed = lines("1\n2\n3", |line| ints::<1>(line).map(|[n]| n));
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+
+        let error = lines("1\n2\nx", |line| ints::<1>(line).map(|[n]| n));
+        assert_eq!(
+            error,
+            Err(ParseError::at_line(3, "expected 1 integers, found 0"))
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "1000\n2000\n\n4000\r\n\r\n\n5000\n6000\n";
+        let blocks: Vec<(usize, &str)> = blocks(input).collect();
+
+        assert_eq!(
+            blocks,
+            vec![(0, "1000\n2000"), (3, "4000"), (6, "5000\n6000")]
+        );
+        assert_eq!(super::blocks("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(
+            integers("Sensor at x=2, y=-18: beacon at x=-2, y=15"),
+            Ok(vec![2, -18, -2, 15])
+        );
+        assert_eq!(integers("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(integers("no numbers - here"), Ok(vec![]));
+        assert_eq!(integers("x=99999999999999999999").unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<3>("1,2,-3"), Ok([1, 2, -3]));
+        assert_eq!(
+            ints::<2>("1,2,3"),
+            Err(ParseError::at_line(1, "expected 2 integers, found 3"))
+        );
+    }
+
+    #[test]
+    fn test_scan() {
+        let step: (u64, u64, u64) = scan("move {} from {} to {}", "move 13 from 1 to 3").unwrap();
+        assert_eq!(step, (13, 1, 3));
+
+        let (name, rate): (String, u32) =
+            scan("Valve {} has flow rate={};", "Valve AA has flow rate=0;").unwrap();
+        assert_eq!((name.as_str(), rate), ("AA", 0));
+
+        let [items] = scan_fields("Starting items: {}", "  Starting items: 79, 98").unwrap();
+        assert_eq!(items, "79, 98");
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let error = scan::<(u64, u64, u64)>("move {} from {} to {}", "move 2 from two to 1");
+        assert_eq!(
+            error,
+            Err(ParseError::new(1, 13, "invalid digit found in string"))
+        );
+
+        let error = scan::<(u64, u64, u64)>("move {} from {} to {}", "move 2 to 1");
+        assert_eq!(error, Err(ParseError::new(1, 6, "expected \"from\"")));
+
+        let error = scan::<(u64,)>("move {}", "jump 2");
+        assert_eq!(error, Err(ParseError::at_line(1, "expected \"move {}\"")));
+    }
+}
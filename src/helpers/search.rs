@@ -0,0 +1,229 @@
+//! Shortest paths over graphs given by a neighbour function, so that the graph never has to be
+//! built. Every search starts from a set of nodes and stops at the first node matching a goal.
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Shortest path found by a search
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found<N, C> {
+    /// Cost of the path, i.e. its number of steps for `bfs`
+    pub cost: C,
+    /// Nodes of the path, from one of the starts to the goal, both included
+    pub path: Vec<N>,
+}
+
+/// Cost of the edges of a weighted search, `Default` being zero
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Nodes reached by a search, each with the index of the node it was reached from
+struct Tree<N> {
+    nodes: Vec<(N, Option<usize>)>,
+}
+
+impl<N: Clone> Tree<N> {
+    fn new() -> Self {
+        Self { nodes: vec![] }
+    }
+
+    fn push(&mut self, node: N, parent: Option<usize>) -> usize {
+        self.nodes.push((node, parent));
+        self.nodes.len() - 1
+    }
+
+    fn node(&self, index: usize) -> &N {
+        &self.nodes[index].0
+    }
+
+    /// Nodes from the root of the tree down to the node at `index`
+    fn path_to(&self, index: usize) -> Vec<N> {
+        let mut path: Vec<N> = std::iter::successors(Some(index), |&index| self.nodes[index].1)
+            .map(|index| self.nodes[index].0.clone())
+            .collect();
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search, for graphs where every step costs 1
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = Tree::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if seen.insert(start.clone()) {
+            queue.push_back((tree.push(start, None), 0));
+        }
+    }
+
+    while let Some((index, steps)) = queue.pop_front() {
+        if goal(tree.node(index)) {
+            return Some(Found {
+                cost: steps,
+                path: tree.path_to(index),
+            });
+        }
+        for next in neighbours(tree.node(index)) {
+            if seen.insert(next.clone()) {
+                queue.push_back((tree.push(next, Some(index)), steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, for graphs whose edges have a non-negative cost. `neighbours` returns
+/// each neighbour with the cost of the edge to it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Found<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, goal, |_| C::default())
+}
+
+/// A* search: Dijkstra's algorithm visiting first the nodes that `heuristic` estimates closer to
+/// a goal. The path is the shortest as long as `heuristic` never overestimates the remaining cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+    mut heuristic: impl FnMut(&N) -> C,
+) -> Option<Found<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = Tree::new();
+    // Index in `tree` and cost of the cheapest known path to each node
+    let mut best: HashMap<N, (usize, C)> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start) {
+            let estimate = heuristic(entry.key());
+            let index = tree.push(entry.key().clone(), None);
+            entry.insert((index, C::default()));
+            queue.push(Reverse((estimate, C::default(), index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let node = tree.node(index);
+        // A cheaper path to the node was found after this one was queued
+        if best[node].0 != index {
+            continue;
+        }
+        if goal(node) {
+            return Some(Found {
+                cost,
+                path: tree.path_to(index),
+            });
+        }
+
+        for (next, weight) in neighbours(node) {
+            let next_cost = cost + weight;
+            if best
+                .get(&next)
+                .is_some_and(|&(_, known)| known <= next_cost)
+            {
+                continue;
+            }
+            let estimate = next_cost + heuristic(&next);
+            let next_index = tree.push(next.clone(), Some(index));
+            best.insert(next, (next_index, next_cost));
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Neighbours on a 5x5 board with a wall on column 2, but for its last row
+    fn open_cells(&(x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .filter(|&(x, y)| x != 2 || y == 4)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let found = bfs([(0, 0)], open_cells, |&cell| cell == (4, 0)).unwrap();
+
+        assert_eq!(found.cost, 12);
+        assert_eq!(found.path.len(), 13);
+        assert_eq!(found.path.first(), Some(&(0, 0)));
+        assert_eq!(found.path.last(), Some(&(4, 0)));
+        assert!(found
+            .path
+            .windows(2)
+            .all(|step| open_cells(&step[0]).contains(&step[1])));
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let found = bfs([(0, 0), (3, 3)], open_cells, |&cell| cell == (4, 0)).unwrap();
+
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.path.first(), Some(&(3, 3)));
+        assert_eq!(bfs([(0, 0)], open_cells, |&cell| cell == (2, 0)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going through `b` is longer but cheaper
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+        let found = dijkstra(['a'], edges, |&node| node == 'd').unwrap();
+
+        assert_eq!(
+            found,
+            Found {
+                cost: 6,
+                path: vec!['a', 'b', 'c', 'd'],
+            }
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let goal = (4, 0);
+        let weighted = |cell: &(i32, i32)| open_cells(cell).into_iter().map(|next| (next, 1));
+        let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+
+        let found = astar([(0, 0)], weighted, |&cell| cell == goal, manhattan).unwrap();
+        let shortest = dijkstra([(0, 0)], weighted, |&cell| cell == goal).unwrap();
+
+        assert_eq!(found.cost, 12);
+        assert_eq!(found.cost, shortest.cost);
+        assert_eq!(found.path.len(), 13);
+    }
+}
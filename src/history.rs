@@ -0,0 +1,308 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::json::{escape, parse_object};
+use crate::record::Record;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// File keeping the timings of every `cargo all` run, one JSON object per line
+pub const HISTORY_PATH: &str = "target/aoc-timings.json";
+
+/// Default slowdown, in percent, above which `--compare` flags a part
+pub const DEFAULT_THRESHOLD: f64 = 20_f64;
+
+/// Timing of one part during one run
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// Milliseconds since the UNIX epoch when the run started, shared by all its entries
+    pub run: u128,
+    pub commit: Option<String>,
+    /// Build profile, "debug" or "release". Only timings of the same profile are compared.
+    pub profile: String,
+    /// Number of timings the duration is the median of
+    pub samples: usize,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub duration_ns: u128,
+}
+
+impl Entry {
+    /// Builds an `Entry` for a solved `Record`
+    pub fn from_record(run: &RunInfo, record: &Record, samples: usize) -> Option<Self> {
+        if !record.solved() {
+            return None;
+        }
+
+        Some(Self {
+            run: run.started_at,
+            commit: run.commit.clone(),
+            profile: run.profile.clone(),
+            samples,
+            year: record.year,
+            day: record.day,
+            part: record.part,
+            duration_ns: record.duration_ns,
+        })
+    }
+
+    /// Serializes the `Entry` as a single JSON line
+    pub fn to_json(&self) -> String {
+        let commit = match &self.commit {
+            Some(commit) => format!("\"{}\"", escape(commit)),
+            None => String::from("null"),
+        };
+
+        format!(
+            "{{\"run\":{},\"commit\":{},\"profile\":\"{}\",\"samples\":{},\"year\":{},\"day\":{},\"part\":{},\"duration_ns\":{}}}",
+            self.run,
+            commit,
+            escape(&self.profile),
+            self.samples,
+            self.year,
+            self.day,
+            self.part,
+            self.duration_ns
+        )
+    }
+
+    /// Whether the duration is the median of a `--bench` run rather than a single timing. Single
+    /// timings are much noisier, so they are only compared with each other.
+    pub fn is_median(&self) -> bool {
+        self.samples > 1
+    }
+
+    /// Parses a JSON line written by `to_json`
+    pub fn from_json(line: &str) -> Option<Self> {
+        let object = parse_object(line.trim())?;
+
+        Some(Self {
+            run: object.get("run")?.as_number()?,
+            commit: object.get("commit")?.as_str().map(String::from),
+            profile: object.get("profile")?.as_str()?.to_string(),
+            samples: object.get("samples")?.as_number()?.try_into().ok()?,
+            // Entries written before seasons were introduced all belong to 2022
+            year: match object.get("year") {
+                Some(year) => year.as_number()?.try_into().ok()?,
+                None => 2022,
+            },
+            day: object.get("day")?.as_number()?.try_into().ok()?,
+            part: object.get("part")?.as_number()?.try_into().ok()?,
+            duration_ns: object.get("duration_ns")?.as_number()?,
+        })
+    }
+}
+
+/// What identifies a run in the history
+pub struct RunInfo {
+    pub started_at: u128,
+    pub commit: Option<String>,
+    pub profile: String,
+}
+
+impl RunInfo {
+    /// Describes the current run: now, on the checked out commit, with the current build profile
+    pub fn current() -> Self {
+        let started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        let profile = if cfg!(debug_assertions) {
+            "debug"
+        } else {
+            "release"
+        };
+
+        Self {
+            started_at,
+            commit: git_commit(),
+            profile: profile.to_string(),
+        }
+    }
+}
+
+/// Returns the short hash of the checked out commit, suffixed with "-dirty" when the working
+/// tree has uncommitted changes. Returns `None` outside of a git repository.
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    let is_dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+
+    Some(if is_dirty {
+        format!("{hash}-dirty")
+    } else {
+        hash
+    })
+}
+
+/// Loads the history at `path`. Unreadable files and malformed lines are skipped.
+pub fn load(path: &str) -> Vec<Entry> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(Entry::from_json)
+        .collect()
+}
+
+/// Appends `entries` to the history at `path`
+pub fn append(path: &str, entries: &[Entry]) -> std::io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for entry in entries {
+        writeln!(file, "{}", entry.to_json())?;
+    }
+
+    Ok(())
+}
+
+/// A part that got slower than its history
+#[derive(Debug, PartialEq, Eq)]
+pub struct Slowdown {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Median of the previous timings of the part
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Slowdown {
+    /// How much slower the part got, in percent
+    pub fn percent(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1_f64) * 100_f64
+    }
+}
+
+/// Compares every `current` entry with the median of the `history` entries of the same part,
+/// build profile and kind of timing, see `Entry::is_median`. Parts are compared one by one, so
+/// that a slowdown of one part is not hidden by the other. Returns the parts that got slower by
+/// more than `threshold` percent.
+pub fn find_slowdowns(history: &[Entry], current: &[Entry], threshold: f64) -> Vec<Slowdown> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let mut previous: Vec<u128> = history
+                .iter()
+                .filter(|old| {
+                    old.year == entry.year
+                        && old.day == entry.day
+                        && old.part == entry.part
+                        && old.profile == entry.profile
+                        && old.is_median() == entry.is_median()
+                })
+                .map(|old| old.duration_ns)
+                .collect();
+            if previous.is_empty() {
+                return None;
+            }
+            previous.sort_unstable();
+
+            let slowdown = Slowdown {
+                year: entry.year,
+                day: entry.day,
+                part: entry.part,
+                baseline: Duration::from_nanos(previous[previous.len() / 2] as u64),
+                current: Duration::from_nanos(entry.duration_ns as u64),
+            };
+
+            (slowdown.percent() > threshold).then_some(slowdown)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(run: u128, day: u8, duration_ns: u128) -> Entry {
+        Entry {
+            run,
+            commit: Some(String::from("abc1234")),
+            profile: String::from("release"),
+            samples: 1,
+            year: 2022,
+            day,
+            part: 1,
+            duration_ns,
+        }
+    }
+
+    #[test]
+    fn test_entry_roundtrip() {
+        let with_commit = entry(1, 15, 1000);
+        let without_commit = Entry {
+            commit: None,
+            ..entry(2, 3, 50)
+        };
+
+        assert_eq!(Entry::from_json(&with_commit.to_json()), Some(with_commit));
+        assert_eq!(
+            Entry::from_json(&without_commit.to_json()),
+            Some(without_commit)
+        );
+        assert_eq!(Entry::from_json("{\"run\":1}"), None);
+
+        let legacy = entry(1, 15, 1000).to_json().replace("\"year\":2022,", "");
+        assert_eq!(Entry::from_json(&legacy), Some(entry(1, 15, 1000)));
+    }
+
+    #[test]
+    fn test_find_slowdowns() {
+        let history = vec![
+            entry(1, 15, 100),
+            entry(2, 15, 110),
+            entry(3, 15, 5000),
+            entry(1, 14, 100),
+            Entry {
+                year: 2021,
+                ..entry(1, 3, 10)
+            },
+            Entry {
+                profile: String::from("debug"),
+                ..entry(1, 8, 100)
+            },
+            Entry {
+                samples: 100,
+                ..entry(1, 9, 100)
+            },
+        ];
+        let current = vec![
+            entry(4, 15, 1100),
+            entry(4, 14, 115),
+            entry(4, 8, 1000),
+            entry(4, 3, 1000),
+            entry(4, 9, 1000),
+        ];
+
+        let slowdowns = find_slowdowns(&history, &current, 20_f64);
+        assert_eq!(
+            slowdowns,
+            vec![Slowdown {
+                year: 2022,
+                day: 15,
+                part: 1,
+                baseline: Duration::from_nanos(110),
+                current: Duration::from_nanos(1100),
+            }]
+        );
+        assert_eq!(slowdowns[0].percent().round(), 900_f64);
+    }
+}
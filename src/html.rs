@@ -0,0 +1,271 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Converts puzzle pages of the Advent of Code website to Markdown.
+
+/// Converts the `<main>` element of a puzzle page to Markdown. Forms and scripts are dropped.
+pub fn to_markdown(page: &str) -> String {
+    let main = between(page, "<main>", "</main>").unwrap_or(page);
+    let mut converter = Converter::default();
+
+    let mut rest = main;
+    while let Some(start) = rest.find('<') {
+        converter.text(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        converter.tag(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+    converter.text(rest);
+
+    let mut markdown = converter.output.trim().to_string();
+    markdown.push('\n');
+    markdown
+}
+
+#[derive(Default)]
+struct Converter {
+    output: String,
+    /// Pending link targets, one per open `<a>`
+    links: Vec<Option<String>>,
+    in_pre: bool,
+    in_code: bool,
+    /// Number of open elements whose content is dropped, e.g. `<script>`
+    skipped: usize,
+}
+
+impl Converter {
+    fn text(&mut self, text: &str) {
+        if self.skipped > 0 {
+            return;
+        }
+
+        let text = decode_entities(text);
+        if self.in_pre {
+            self.output.push_str(&text);
+            return;
+        }
+
+        let text = collapse_whitespace(&text);
+        let text = if self.in_code { text } else { escape(&text) };
+        if self.output.ends_with('\n') || self.output.is_empty() {
+            self.output.push_str(text.trim_start());
+        } else {
+            self.output.push_str(&text);
+        }
+    }
+
+    fn tag(&mut self, tag: &str) {
+        let is_closing = tag.starts_with('/');
+        let tag = tag.trim_start_matches('/').trim_end_matches('/');
+        let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let name = name.to_lowercase();
+
+        if matches!(name.as_str(), "script" | "style" | "form") {
+            if is_closing {
+                self.skipped = self.skipped.saturating_sub(1);
+            } else {
+                self.skipped += 1;
+            }
+            return;
+        }
+        if self.skipped > 0 {
+            return;
+        }
+
+        match (name.as_str(), is_closing) {
+            ("h1" | "h2" | "h3", false) => self.block(),
+            ("h1" | "h2" | "h3", true) => {
+                let title = self
+                    .output
+                    .split('\n')
+                    .next_back()
+                    .unwrap_or("")
+                    .to_string();
+                self.output.truncate(self.output.len() - title.len());
+                let title = title
+                    .strip_prefix('-')
+                    .map_or(title.clone(), |rest| format!("\\-{rest}"));
+                self.output.push_str(&format!("{title}\n----------"));
+                self.block();
+            }
+            ("p" | "article" | "ul" | "ol", _) => self.block(),
+            ("li", false) => {
+                self.line();
+                self.output.push_str("* ");
+            }
+            ("br", _) => self.line(),
+            ("pre", false) => {
+                self.block();
+                self.output.push_str("```\n");
+                self.in_pre = true;
+            }
+            ("pre", true) => {
+                self.in_pre = false;
+                self.line();
+                self.output.push_str("```");
+                self.block();
+            }
+            ("code", _) if self.in_pre => {}
+            ("code", _) => {
+                self.in_code = !is_closing;
+                self.output.push('`');
+            }
+            ("em" | "strong", _) if self.in_pre => {}
+            ("em" | "strong", _) => self.output.push('*'),
+            ("a", false) => {
+                self.links.push(attribute(attributes, "href"));
+                self.output.push('[');
+            }
+            ("a", true) => match self.links.pop().flatten() {
+                Some(href) => self.output.push_str(&format!("]({href})")),
+                None => self.output.push(']'),
+            },
+            _ => {}
+        }
+    }
+
+    /// Ends the current line
+    fn line(&mut self) {
+        let trimmed = self.output.trim_end_matches([' ', '\t']).len();
+        self.output.truncate(trimmed);
+        if !self.output.is_empty() && !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+    }
+
+    /// Ends the current block with a blank line
+    fn block(&mut self) {
+        self.line();
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+}
+
+/// Returns the part of `s` between the first `start` and the next `end`
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = s.find(start)? + start.len();
+    let to = s[from..].find(end)? + from;
+    Some(&s[from..to])
+}
+
+/// Returns the value of `name` among the `attributes` of a tag. The name must start an
+/// attribute, so `href` does not match `data-href`.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let pattern = format!("{name}=");
+    let (start, _) = attributes.match_indices(&pattern).find(|&(start, _)| {
+        attributes[..start]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace)
+    })?;
+    let value = &attributes[start + pattern.len()..];
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = value[1..].split(quote).next()?;
+    Some(decode_entities(value))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let character = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => name.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+
+        match (character, entity) {
+            (Some(character), Some((_, end))) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !collapsed.ends_with(' ') {
+                collapsed.push(' ');
+            }
+        } else {
+            collapsed.push(c);
+        }
+    }
+    collapsed
+}
+
+/// Escapes the characters that Markdown would read as emphasis
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('*', "\\*")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_markdown() {
+        let page = r#"<html><head><script>var x = 1;</script></head><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>Santa's reindeer eat <a href="/2018/day/25">magical
+  energy</a> &amp; <em>star</em> fruit: 2 * 3.</p>
+<pre><code>1000
+<em>2000</em>
+</code></pre>
+<ul><li>The first Elf has <code><em>6000</em></code> Calories.</li><li>The second Elf has <code>&lt;4000&gt;</code>.</li></ul>
+</article>
+<form method="post"><input type="submit" value="Submit"/></form>
+<p>Your puzzle answer was <code>24000</code>.</p></main></body></html>"#;
+
+        assert_eq!(
+            to_markdown(page),
+            "\\--- Day 1: Calorie Counting ---\n----------\n\nSanta's reindeer eat [magical energy](/2018/day/25) & *star* fruit: 2 \\* 3.\n\n```\n1000\n2000\n```\n\n* The first Elf has `*6000*` Calories.\n* The second Elf has `<4000>`.\n\nYour puzzle answer was `24000`.\n"
+        );
+    }
+
+    #[test]
+    fn test_attribute() {
+        let attributes = r#"data-href="/wrong" class='x' href="/2022/day/1?a=1&amp;b=2""#;
+        assert_eq!(
+            attribute(attributes, "href"),
+            Some("/2022/day/1?a=1&b=2".to_string())
+        );
+        assert_eq!(attribute(attributes, "class"), Some("x".to_string()));
+        assert_eq!(attribute(r#"data-href="/wrong""#, "href"), None);
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#39;c&#x27; &unknown; &"),
+            "a <b> 'c' &unknown; &"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Just enough JSON to read and write the flat, one-line objects used by the template tooling.
+
+/// Escapes `s` to be written inside a JSON string
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A JSON value, as found in flat objects
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Str(String),
+    Number(u128),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    pub fn as_number(&self) -> Option<u128> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// The fields of a flat JSON object, in order
+pub struct Object(Vec<(String, Value)>);
+
+impl Object {
+    /// Returns the value of field `key`
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Parses a flat JSON object made of string, unsigned integer, boolean and null values
+pub fn parse_object(s: &str) -> Option<Object> {
+    let mut chars = s.strip_prefix('{')?.strip_suffix('}')?.chars().peekable();
+    let mut fields = vec![];
+
+    loop {
+        skip_whitespace(&mut chars);
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let key = parse_string(&mut chars)?;
+        skip_whitespace(&mut chars);
+        if chars.next()? != ':' {
+            return None;
+        }
+        skip_whitespace(&mut chars);
+
+        let value = match chars.peek()? {
+            '"' => Value::Str(parse_string(&mut chars)?),
+            c if c.is_ascii_digit() => {
+                let mut digits = String::new();
+                while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(c);
+                }
+                Value::Number(digits.parse().ok()?)
+            }
+            _ => {
+                let word: String =
+                    std::iter::from_fn(|| chars.next_if(char::is_ascii_alphabetic)).collect();
+                match word.as_str() {
+                    "true" => Value::Bool(true),
+                    "false" => Value::Bool(false),
+                    "null" => Value::Null,
+                    _ => return None,
+                }
+            }
+        };
+        fields.push((key, value));
+
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some(',') => continue,
+            None => break,
+            _ => return None,
+        }
+    }
+
+    Some(Object(fields))
+}
+
+fn parse_string(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+
+    let mut s = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(s),
+            '\\' => match chars.next()? {
+                'n' => s.push('\n'),
+                'r' => s.push('\r'),
+                't' => s.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    s.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => s.push(c),
+            },
+            c => s.push(c),
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_object() {
+        let object =
+            parse_object(r#"{"name": "a\"b\nc", "count": 12, "ok": true, "none": null}"#).unwrap();
+
+        assert_eq!(object.get("name").unwrap().as_str(), Some("a\"b\nc"));
+        assert_eq!(object.get("count").unwrap().as_number(), Some(12));
+        assert_eq!(object.get("ok"), Some(&Value::Bool(true)));
+        assert_eq!(object.get("none"), Some(&Value::Null));
+        assert_eq!(object.get("missing"), None);
+        assert!(parse_object("[1, 2]").is_none());
+        assert!(parse_object(r#"{"count": -1}"#).is_none());
+    }
+
+    #[test]
+    fn test_escape() {
+        let escaped = escape("a\"b\\c\nd\u{1}");
+        assert_eq!(escaped, r#"a\"b\\c\nd\u0001"#);
+
+        let object = parse_object(&format!("{{\"s\":\"{escaped}\"}}")).unwrap();
+        assert_eq!(object.get("s").unwrap().as_str(), Some("a\"b\\c\nd\u{1}"));
+    }
+}
@@ -2,44 +2,232 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::args::parse_timeout;
+use advent_of_code::bench::{self, Timings};
+use advent_of_code::days;
+use advent_of_code::history::{self, Entry, RunInfo};
+use advent_of_code::record::{self, RECORD_FLAG};
+use advent_of_code::runner::{self, DayOutcome, Isolated};
+use advent_of_code::{answers, ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    year: u16,
+    parallel: bool,
+    bench: Option<usize>,
+    compare: bool,
+    threshold: f64,
+    timeout: Option<Duration>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = pico_args::Arguments::from_env();
+    let error = |e: pico_args::Error| e.to_string();
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])
+            .map_err(error)?
+            .unwrap_or_else(days::latest_year),
+        parallel: args.contains(["-p", "--parallel"]),
+        bench: args.opt_value_from_str(["-b", "--bench"]).map_err(error)?,
+        compare: args.contains(["-c", "--compare"]),
+        threshold: args
+            .opt_value_from_str(["-t", "--threshold"])
+            .map_err(error)?
+            .unwrap_or(history::DEFAULT_THRESHOLD),
+        timeout: args
+            .opt_value_from_fn("--timeout", parse_timeout)
+            .map_err(error)?
+            .unwrap_or(Some(runner::DEFAULT_TIMEOUT)),
+    };
+    // Read by `record::is_enabled`
+    args.contains(RECORD_FLAG);
+
+    if let Some(argument) = args.finish().first() {
+        return Err(format!("unexpected argument {argument:?}"));
+    }
+    Ok(parsed)
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+    let Some(season) = days::season(args.year) else {
+        eprintln!("No season registered for {}.", args.year);
+        process::exit(1);
+    };
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    let runs = match args.bench {
+        Some(iterations) => runner::bench_days(season.days, iterations, args.timeout),
+        None => runner::run_days(season.days, args.parallel, args.timeout)
+            .into_iter()
+            .map(|(day, results)| {
+                let results = results
+                    .into_iter()
+                    .map(|result| result.map(|record| (record, None)))
+                    .collect();
+                (day, results)
+            })
+            .collect(),
+    };
+    let mut regressions = vec![];
+    let mut outcomes = vec![];
+    let mut timings = vec![];
 
+    let total: f64 = (1..=25)
+        .map(|day| {
             println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
+            println!("{ANSI_BOLD}| Day {day:02} |{ANSI_RESET}");
             println!("----------");
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+            let results = runs
+                .iter()
+                .find(|(run_day, _)| *run_day == day)
+                .map(|(_, results)| results.as_slice());
+            let outcome = DayOutcome::of(results);
+            let results = results.unwrap_or_default();
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+            match outcome {
+                DayOutcome::NotImplemented if results.is_empty() => println!("Not implemented."),
+                DayOutcome::NoInput => println!("No input."),
+                _ => {
+                    let timing = |part: usize| {
+                        results
+                            .get(part)
+                            .and_then(Isolated::finished)
+                            .filter(|(record, _)| record.solved())
+                            .map(|(record, _)| record.duration())
+                    };
+                    timings.push(Timings {
+                        year: season.year,
+                        day,
+                        part_one: timing(0),
+                        part_two: timing(1),
+                    });
                 }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
             }
+            outcomes.push((day, outcome));
+
+            (1..)
+                .zip(results)
+                .map(|(part, result)| {
+                    println!("🎄 {ANSI_BOLD}Part {part}{ANSI_RESET} 🎄");
+                    let (record, stats) = match result {
+                        Isolated::Finished(measurement) => measurement,
+                        Isolated::Panicked(message) => {
+                            println!("panicked: {message}");
+                            return 0_f64;
+                        }
+                        Isolated::TimedOut(timeout) => {
+                            println!("timed out after {timeout:.2?}");
+                            return 0_f64;
+                        }
+                    };
+
+                    let verdict = answers::check(record);
+                    if record.failed() {
+                        println!("{}", record.summary());
+                    } else {
+                        println!("{} {}", record.summary(), verdict);
+                    }
+                    if let Some(stats) = stats {
+                        println!("{ANSI_ITALIC}{stats}{ANSI_RESET}");
+                    }
+                    if record::is_enabled() {
+                        println!("{}", record.to_json());
+                    }
+                    if verdict.is_regression() {
+                        regressions.push((record.day, record.part));
+                    }
+
+                    if record.solved() {
+                        record.duration_ns as f64 / 1_000_000_f64
+                    } else {
+                        0_f64
+                    }
+                })
+                .sum::<f64>()
         })
         .sum();
 
     println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+
+    let allocations = runs
+        .iter()
+        .flat_map(|(_, results)| results)
+        .filter_map(Isolated::finished)
+        .filter_map(|(record, _)| record.allocations)
+        .reduce(|total, allocations| total.combine(&allocations));
+    if let Some(allocations) = allocations {
+        println!("{ANSI_BOLD}Memory:{ANSI_RESET} {ANSI_ITALIC}{allocations}{ANSI_RESET}");
+    }
+
+    if args.bench.is_some() {
+        match bench::update_readme("README.md", &timings) {
+            Ok(_) => println!("Wrote timings to \"README.md\"."),
+            Err(e) => eprintln!("Failed to write timings to \"README.md\": {e}"),
+        }
+    }
+
+    let run = RunInfo::current();
+    let entries: Vec<Entry> = runs
+        .iter()
+        .flat_map(|(_, results)| results)
+        .filter_map(Isolated::finished)
+        .filter_map(|(record, stats)| {
+            Entry::from_record(&run, record, stats.as_ref().map_or(1, |stats| stats.runs))
+        })
+        .collect();
+    let previous = history::load(history::HISTORY_PATH);
+    if let Err(e) = history::append(history::HISTORY_PATH, &entries) {
+        eprintln!(
+            "Failed to write timings to \"{}\": {e}",
+            history::HISTORY_PATH
+        );
+    }
+
+    let slowdowns = if args.compare {
+        history::find_slowdowns(&previous, &entries, args.threshold)
+    } else {
+        vec![]
+    };
+    for slowdown in &slowdowns {
+        eprintln!(
+            "🐢 Day {:02} part {} got {:.0}% slower: {:.2?} (was {:.2?}).",
+            slowdown.day,
+            slowdown.part,
+            slowdown.percent(),
+            slowdown.current,
+            slowdown.baseline
+        );
+    }
+
+    println!("{ANSI_BOLD}Summary:{ANSI_RESET}");
+    for (day, outcome) in &outcomes {
+        let color = match outcome {
+            DayOutcome::Ok => ANSI_GREEN,
+            outcome if outcome.is_failure() => ANSI_RED,
+            _ => ANSI_DIM,
+        };
+        println!("{color}Day {day:02}: {outcome}{ANSI_RESET}");
+    }
+
+    let failed = outcomes.iter().any(|(_, outcome)| outcome.is_failure());
+    for (day, part) in &regressions {
+        eprintln!("❌ Day {day:02} part {part} does not match its recorded answer.");
+    }
+    if failed || !regressions.is_empty() {
+        process::exit(1);
+    }
+
+    if !slowdowns.is_empty() {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,328 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Renders the Markdown of puzzle descriptions for the terminal.
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::env;
+
+/// Width used when the terminal width is unknown
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// Widest text rendered, wider lines get hard to read
+pub const MAX_WIDTH: usize = 100;
+
+const INDENT: &str = "    ";
+
+/// Returns the width to wrap text at, read from `COLUMNS` when the shell exports it
+pub fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH)
+        .min(MAX_WIDTH)
+}
+
+/// Renders `markdown` with ANSI styles: headings and emphasis in bold, inline code in italic,
+/// indented code blocks, and paragraphs and list items wrapped at `width` columns
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut output: Vec<String> = vec![];
+    let mut block: Option<Block> = None;
+    let mut lines = markdown.lines().peekable();
+    let mut in_code = false;
+
+    while let Some(line) = lines.next() {
+        if line.trim_start().starts_with("```") {
+            flush(&mut block, &mut output, width);
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            output.push(format!("{INDENT}{line}").trim_end().to_string());
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            flush(&mut block, &mut output, width);
+            if output.last().is_some_and(|last| !last.is_empty()) {
+                output.push(String::new());
+            }
+            continue;
+        }
+
+        let is_setext = lines.peek().is_some_and(|next| is_underline(next));
+        if is_setext || line.starts_with('#') {
+            flush(&mut block, &mut output, width);
+            if is_setext {
+                lines.next();
+            }
+            let title = line.trim_start_matches('#').trim();
+            let words: Vec<String> = words(title).into_iter().map(|word| word.text).collect();
+            output.push(format!("{ANSI_BOLD}{}{ANSI_RESET}", words.join(" ")));
+            continue;
+        }
+
+        if let Some((indent, item)) = list_item(line) {
+            flush(&mut block, &mut output, width);
+            block = Some(Block {
+                first_prefix: format!("{}• ", " ".repeat(indent)),
+                prefix: " ".repeat(indent + 2),
+                text: item.to_string(),
+            });
+            continue;
+        }
+
+        match &mut block {
+            Some(block) => {
+                block.text.push(' ');
+                block.text.push_str(line.trim());
+            }
+            None => {
+                block = Some(Block {
+                    first_prefix: String::new(),
+                    prefix: String::new(),
+                    text: line.trim().to_string(),
+                })
+            }
+        }
+    }
+    flush(&mut block, &mut output, width);
+
+    while output.last().is_some_and(|last| last.is_empty()) {
+        output.pop();
+    }
+    output.join("\n")
+}
+
+/// Text of a paragraph or list item, wrapped once complete
+struct Block {
+    first_prefix: String,
+    prefix: String,
+    text: String,
+}
+
+/// Wraps the pending `block`, if any, into `output`
+fn flush(block: &mut Option<Block>, output: &mut Vec<String>, width: usize) {
+    if let Some(block) = block.take() {
+        output.extend(wrap(&block, width));
+    }
+}
+
+/// Whether `line` underlines the heading above it
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+/// Splits a list item into its indentation and its text
+fn list_item(line: &str) -> Option<(usize, &str)> {
+    let text = line.trim_start();
+    let indent = line.len() - text.len();
+
+    ["* ", "- ", "+ "]
+        .iter()
+        .find_map(|bullet| text.strip_prefix(bullet))
+        .map(|item| (indent, item))
+}
+
+/// A word with its ANSI styles, and the number of columns it takes
+#[derive(Default)]
+struct Word {
+    text: String,
+    width: usize,
+}
+
+/// Wraps a `Block` into lines of at most `width` columns, unless a single word is wider
+fn wrap(block: &Block, width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = block.first_prefix.clone();
+    let mut line_width = block.first_prefix.chars().count();
+    let mut is_empty = true;
+
+    for word in words(&block.text) {
+        if !is_empty && line_width + 1 + word.width > width {
+            lines.push(line);
+            line = block.prefix.clone();
+            line_width = block.prefix.chars().count();
+            is_empty = true;
+        }
+        if !is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(&word.text);
+        line_width += word.width;
+        is_empty = false;
+    }
+    lines.push(line);
+
+    lines
+}
+
+/// Splits inline Markdown into styled `Words`. Emphasis is shown in bold and code in italic.
+/// Links are replaced by their text.
+fn words(text: &str) -> Vec<Word> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = vec![];
+    let mut word = Word::default();
+    let mut style = Style::default();
+    let mut links = 0;
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let prev = i.checked_sub(1).map(|k| chars[k]);
+        let next = chars.get(i + 1).copied();
+
+        match c {
+            '\\' if !style.code && next.is_some_and(|next| next.is_ascii_punctuation()) => {
+                word.push(chars[i + 1], style);
+                i += 1;
+            }
+            '`' => {
+                style.code = !style.code;
+                word.restyle(style);
+            }
+            // `**` delimits strong emphasis, rendered like emphasis
+            '*' if next == Some('*') => {
+                style.bold = !style.bold;
+                word.restyle(style);
+                i += 1;
+            }
+            '*' if !style.bold && next.is_some_and(|next| !next.is_whitespace()) => {
+                style.bold = true;
+                word.restyle(style);
+            }
+            '*' if style.bold && prev.is_some_and(|prev| !prev.is_whitespace()) => {
+                style.bold = false;
+                word.restyle(style);
+            }
+            '[' if !style.code && is_link(&chars[i..]) => links += 1,
+            ']' if !style.code && links > 0 && next == Some('(') => {
+                links -= 1;
+                let mut depth = 0;
+                while let Some(&c) = chars.get(i + 1) {
+                    i += 1;
+                    match c {
+                        '(' => depth += 1,
+                        ')' if depth == 1 => break,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                }
+            }
+            c if c.is_whitespace() => {
+                if word.width > 0 {
+                    words.push(std::mem::take(&mut word).end(style));
+                }
+            }
+            c => word.push(c, style),
+        }
+        i += 1;
+    }
+    if word.width > 0 {
+        words.push(word.end(style));
+    }
+
+    words
+}
+
+/// Whether `chars` start with a link, e.g. `[text](url)`
+fn is_link(chars: &[char]) -> bool {
+    let mut depth = 0;
+    for (i, c) in chars.iter().enumerate() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 1 => return chars.get(i + 1) == Some(&'('),
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Inline styles active at some point of a text
+#[derive(Clone, Copy, Default)]
+struct Style {
+    bold: bool,
+    code: bool,
+}
+
+impl Style {
+    fn is_plain(&self) -> bool {
+        !self.bold && !self.code
+    }
+
+    /// ANSI codes enabling the style
+    fn codes(&self) -> String {
+        let mut codes = String::new();
+        if self.bold {
+            codes.push_str(ANSI_BOLD);
+        }
+        if self.code {
+            codes.push_str(ANSI_ITALIC);
+        }
+        codes
+    }
+}
+
+impl Word {
+    /// Appends a visible character styled with `style`
+    fn push(&mut self, c: char, style: Style) {
+        if self.width == 0 {
+            self.text.push_str(&style.codes());
+        }
+        self.text.push(c);
+        self.width += 1;
+    }
+
+    /// Switches to `style` for the next characters of the word
+    fn restyle(&mut self, style: Style) {
+        if self.width > 0 {
+            self.text.push_str(ANSI_RESET);
+            self.text.push_str(&style.codes());
+        }
+    }
+
+    /// Resets the styles still active at the end of the word
+    fn end(mut self, style: Style) -> Self {
+        if !style.is_plain() {
+            self.text.push_str(ANSI_RESET);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip_ansi(s: &str) -> String {
+        [ANSI_BOLD, ANSI_ITALIC, ANSI_RESET]
+            .iter()
+            .fold(s.to_string(), |s, code| s.replace(code, ""))
+    }
+
+    #[test]
+    fn test_render_blocks() {
+        let markdown = "\\--- Day 1: Calorie Counting ---\n----------\n\nSanta's reindeer eat [magical energy](/2018/day/25) to deliver presents.\n\n* The first Elf carries a total of `*6000*` Calories.\n* The second Elf carries `4000`.\n\n```\n1000\n2000\n```\n";
+        let rendered = render(markdown, 30);
+
+        assert!(rendered.starts_with(&format!("{ANSI_BOLD}--- Day 1: Calorie")));
+        assert_eq!(
+            strip_ansi(&rendered),
+            "--- Day 1: Calorie Counting ---\n\nSanta's reindeer eat magical\nenergy to deliver presents.\n\n• The first Elf carries a\n  total of 6000 Calories.\n• The second Elf carries 4000.\n\n    1000\n    2000"
+        );
+    }
+
+    #[test]
+    fn test_words_styles() {
+        let words = words("a *big* `old * 5` \\* [x](f(1)) [y]");
+        let texts: Vec<String> = words.iter().map(|word| strip_ansi(&word.text)).collect();
+
+        assert_eq!(texts, vec!["a", "big", "old", "*", "5", "*", "x", "[y]"]);
+        assert_eq!(words[1].text, format!("{ANSI_BOLD}big{ANSI_RESET}"));
+        assert!(words[3].text.contains(ANSI_ITALIC));
+        assert_eq!(words[3].width, 1);
+    }
+}
@@ -0,0 +1,254 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Opt-in counting of heap allocations, enabled by the `count-allocations` feature.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+
+/// Global allocator that forwards to the `System` allocator and counts what goes through it.
+/// Counts are shared by all threads, so that a part is charged for what the threads it spreads
+/// its work over allocate, e.g. with rayon. Parts measured side by side would be charged for
+/// each other's allocations, which is why days run one after another when allocations are
+/// counted.
+pub struct CountingAllocator {
+    /// Bytes in use
+    current: AtomicIsize,
+    /// Most bytes in use at once since the last measure started
+    peak: AtomicIsize,
+    total: AtomicUsize,
+    count: AtomicUsize,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        Self {
+            current: AtomicIsize::new(0),
+            peak: AtomicIsize::new(0),
+            total: AtomicUsize::new(0),
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    fn record_alloc(&self, size: usize) {
+        let current = self.current.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+        self.total.fetch_add(size, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(&self, size: usize) {
+        self.current.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+
+    /// Runs `f` and returns what was allocated while it ran, on any thread. The peak is counted
+    /// from the bytes in use when `f` starts, so memory held by the caller is left out.
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Allocations) {
+        let baseline = self.current.load(Ordering::Relaxed);
+        self.peak.store(baseline, Ordering::Relaxed);
+        let total = self.total.load(Ordering::Relaxed);
+        let count = self.count.load(Ordering::Relaxed);
+
+        let result = f();
+
+        let allocations = Allocations {
+            peak_bytes: (self.peak.load(Ordering::Relaxed) - baseline).max(0) as usize,
+            total_bytes: self.total.load(Ordering::Relaxed) - total,
+            count: self.count.load(Ordering::Relaxed) - count,
+        };
+        (result, allocations)
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.record_dealloc(layout.size());
+            self.record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+/// Whether allocations are counted, i.e. the crate was built with `--features count-allocations`
+pub const fn is_enabled() -> bool {
+    cfg!(feature = "count-allocations")
+}
+
+/// Runs `f` and returns what it allocated, or `None` when allocations are not counted
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let (result, allocations) = ALLOCATOR.measure(f);
+        (result, Some(allocations))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+/// Heap usage of a solver
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// Most bytes in use at once
+    pub peak_bytes: usize,
+    /// Bytes allocated over the whole run, reallocations included
+    pub total_bytes: usize,
+    /// Number of allocations and reallocations
+    pub count: usize,
+}
+
+impl Allocations {
+    /// Combines the usage of separate runs: the highest peak, and the sums of the rest
+    pub fn combine(&self, other: &Allocations) -> Allocations {
+        Allocations {
+            peak_bytes: self.peak_bytes.max(other.peak_bytes),
+            total_bytes: self.total_bytes + other.total_bytes,
+            count: self.count + other.count,
+        }
+    }
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak: {}, allocated: {} in {} allocations",
+            format_bytes(self.peak_bytes),
+            format_bytes(self.total_bytes),
+            self.count
+        )
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.50KiB`
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.2}{}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_measure() {
+        let allocator = CountingAllocator::new();
+        let small = Layout::from_size_align(16, 8).unwrap();
+        let large = Layout::from_size_align(100, 8).unwrap();
+
+        let in_use = allocator.current.load(Ordering::Relaxed);
+        // Held by the caller, not part of the measure
+        let held = unsafe { allocator.alloc(small) };
+        let ((), allocations) = allocator.measure(|| unsafe {
+            let a = allocator.alloc(large);
+            allocator.dealloc(a, large);
+            let b = allocator.alloc(small);
+            let b = allocator.realloc(b, small, 32);
+            allocator.dealloc(b, Layout::from_size_align(32, 8).unwrap());
+        });
+        unsafe { allocator.dealloc(held, small) };
+
+        assert_eq!(
+            allocations,
+            Allocations {
+                peak_bytes: 100,
+                total_bytes: 148,
+                count: 3,
+            }
+        );
+        assert_eq!(allocator.current.load(Ordering::Relaxed), in_use);
+    }
+
+    #[test]
+    fn test_measure_other_threads() {
+        let allocator = CountingAllocator::new();
+        let layout = Layout::from_size_align(64, 8).unwrap();
+
+        let ((), allocations) = allocator.measure(|| {
+            (0..8).into_par_iter().for_each(|_| unsafe {
+                let ptr = allocator.alloc(layout);
+                allocator.dealloc(ptr, layout);
+            });
+        });
+
+        assert_eq!(allocations.total_bytes, 8 * 64);
+        assert_eq!(allocations.count, 8);
+    }
+
+    #[test]
+    fn test_combine() {
+        let a = Allocations {
+            peak_bytes: 10,
+            total_bytes: 20,
+            count: 2,
+        };
+        let b = Allocations {
+            peak_bytes: 5,
+            total_bytes: 50,
+            count: 1,
+        };
+
+        assert_eq!(
+            a.combine(&b),
+            Allocations {
+                peak_bytes: 10,
+                total_bytes: 70,
+                count: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00MiB");
+    }
+}
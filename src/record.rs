@@ -0,0 +1,254 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::json::{escape, parse_object, Value};
+use crate::memory::Allocations;
+use crate::{ANSI_ITALIC, ANSI_RESET};
+use std::env;
+use std::time::Duration;
+
+/// Environment variable that makes day binaries emit one JSON `Record` per part
+pub const RECORD_ENV: &str = "AOC_RECORD";
+
+/// Command line flag that makes day binaries emit one JSON `Record` per part
+pub const RECORD_FLAG: &str = "--record";
+
+/// Machine-readable result of running one part of a day
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Why the solver failed, if it did
+    pub error: Option<String>,
+    pub duration_ns: u128,
+    /// Heap usage of the solver, when allocations are counted
+    pub allocations: Option<Allocations>,
+}
+
+impl Record {
+    /// Whether the solver returned an answer
+    pub fn solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    /// Whether the solver returned an error
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Returns the measured duration as a `Duration`
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns as u64)
+    }
+
+    /// Human-readable answer line, e.g. `6 (elapsed: 37.03µs)`, followed by the heap usage
+    /// when allocations are counted
+    pub fn summary(&self) -> String {
+        if let Some(error) = &self.error {
+            return format!("failed: {error}");
+        }
+
+        let allocations = self
+            .allocations
+            .map_or(String::new(), |allocations| format!(", {allocations}"));
+        match &self.answer {
+            Some(answer) => format!(
+                "{} {}(elapsed: {:.2?}{}){}",
+                answer,
+                ANSI_ITALIC,
+                self.duration(),
+                allocations,
+                ANSI_RESET
+            ),
+            None => String::from("not solved."),
+        }
+    }
+
+    /// Serializes the `Record` as a single JSON line. Heap usage is included only when counted.
+    pub fn to_json(&self) -> String {
+        let string_or_null = |value: &Option<String>| match value {
+            Some(value) => format!("\"{}\"", escape(value)),
+            None => String::from("null"),
+        };
+
+        let allocations = self.allocations.map_or(String::new(), |allocations| {
+            format!(
+                ",\"peak_bytes\":{},\"allocated_bytes\":{},\"allocations\":{}",
+                allocations.peak_bytes, allocations.total_bytes, allocations.count
+            )
+        });
+
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"solved\":{},\"answer\":{},\"error\":{},\"duration_ns\":{}{}}}",
+            self.year,
+            self.day,
+            self.part,
+            self.solved(),
+            string_or_null(&self.answer),
+            string_or_null(&self.error),
+            self.duration_ns,
+            allocations
+        )
+    }
+
+    /// Parses a JSON line written by `to_json`. Returns `None` for any other line.
+    pub fn from_json(line: &str) -> Option<Self> {
+        let object = parse_object(line.trim())?;
+
+        let string_or_null = |key: &str| match object.get(key)? {
+            Value::Str(value) => Some(Some(value.clone())),
+            Value::Null => Some(None),
+            _ => None,
+        };
+        let size = |key: &str| -> Option<usize> { object.get(key)?.as_number()?.try_into().ok() };
+        let allocations = match (
+            size("peak_bytes"),
+            size("allocated_bytes"),
+            size("allocations"),
+        ) {
+            (Some(peak_bytes), Some(total_bytes), Some(count)) => Some(Allocations {
+                peak_bytes,
+                total_bytes,
+                count,
+            }),
+            _ => None,
+        };
+
+        Some(Self {
+            year: object.get("year")?.as_number()?.try_into().ok()?,
+            day: object.get("day")?.as_number()?.try_into().ok()?,
+            part: object.get("part")?.as_number()?.try_into().ok()?,
+            answer: string_or_null("answer")?,
+            error: string_or_null("error")?,
+            duration_ns: object.get("duration_ns")?.as_number()?,
+            allocations,
+        })
+    }
+}
+
+/// Whether `Records` should be emitted, either through `RECORD_ENV` or `RECORD_FLAG`
+pub fn is_enabled() -> bool {
+    let from_env = env::var(RECORD_ENV).is_ok_and(|value| !value.is_empty() && value != "0");
+
+    from_env || env::args().any(|arg| arg == RECORD_FLAG)
+}
+
+/// Splits an output into the `Records` it contains and the remaining human-readable lines
+pub fn split_output(output: &str) -> (Vec<Record>, Vec<&str>) {
+    let mut records = vec![];
+    let mut lines = vec![];
+
+    for line in output.lines() {
+        match Record::from_json(line) {
+            Some(record) => records.push(record),
+            None => lines.push(line),
+        }
+    }
+
+    (records, lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_roundtrip() {
+        let record = Record {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: Some(String::from("##..\n\"#.\"\t\\")),
+            error: None,
+            duration_ns: 37030,
+            allocations: None,
+        };
+
+        let json = record.to_json();
+        assert!(!json.contains('\n'));
+        assert_eq!(Record::from_json(&json), Some(record));
+    }
+
+    #[test]
+    fn test_record_allocations() {
+        let record = Record {
+            year: 2022,
+            day: 14,
+            part: 1,
+            answer: Some(String::from("24")),
+            error: None,
+            duration_ns: 1500,
+            allocations: Some(Allocations {
+                peak_bytes: 1536,
+                total_bytes: 4096,
+                count: 12,
+            }),
+        };
+
+        assert_eq!(
+            record.summary(),
+            format!("24 {ANSI_ITALIC}(elapsed: 1.50µs, peak: 1.50KiB, allocated: 4.00KiB in 12 allocations){ANSI_RESET}")
+        );
+        assert!(record.to_json().ends_with(
+            r#""duration_ns":1500,"peak_bytes":1536,"allocated_bytes":4096,"allocations":12}"#
+        ));
+        assert_eq!(Record::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_record_unsolved() {
+        let record = Record {
+            year: 2022,
+            day: 16,
+            part: 1,
+            answer: None,
+            error: None,
+            duration_ns: 50,
+            allocations: None,
+        };
+
+        assert_eq!(
+            record.to_json(),
+            r#"{"year":2022,"day":16,"part":1,"solved":false,"answer":null,"error":null,"duration_ns":50}"#
+        );
+        assert_eq!(Record::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_record_failed() {
+        let record = Record {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: None,
+            error: Some(String::from("line 3, column 1: invalid step")),
+            duration_ns: 50,
+            allocations: None,
+        };
+
+        assert!(record.failed());
+        assert_eq!(record.summary(), "failed: line 3, column 1: invalid step");
+        assert_eq!(Record::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn test_split_output() {
+        let output = "🎄 Part 1 🎄\n6 (elapsed: 37.03µs)\n{\"year\":2022,\"day\":1,\"part\":1,\"solved\":true,\"answer\":\"6\",\"error\":null,\"duration_ns\":37030}";
+        let (records, lines) = split_output(output);
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].answer.as_deref(), Some("6"));
+        assert_eq!(records[0].duration_ns, 37030);
+        assert_eq!(lines, vec!["🎄 Part 1 🎄", "6 (elapsed: 37.03µs)"]);
+    }
+
+    #[test]
+    fn test_from_json_rejects_other_lines() {
+        assert_eq!(Record::from_json("not solved."), None);
+        assert_eq!(Record::from_json("{}"), None);
+        assert_eq!(Record::from_json(r#"{"day":"1"}"#), None);
+    }
+}
@@ -0,0 +1,532 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{self, Verdict};
+use crate::args::{InputSource, SolveArgs};
+use crate::bench::Stats;
+use crate::days::{self, Day};
+use crate::examples;
+use crate::helpers::{get_examples_dir, get_path, try_read_input};
+use crate::memory;
+use crate::record::{self, Record};
+use crate::variants;
+use crate::{ANSI_BOLD, ANSI_RESET};
+use std::any::Any;
+use std::fmt::Display;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Runs one `part` of `day` on `input`. Only the solver itself is timed, and its allocations
+/// counted when enabled.
+pub fn run_part(day: &dyn Day, part: u8, input: &str) -> Record {
+    run_solver(day, part, || match part {
+        1 => day.part_one(input),
+        2 => day.part_two(input),
+        _ => panic!("Invalid part {part}"),
+    })
+}
+
+/// Runs `solve`, an implementation of `part` of `day`, into a `Record`
+pub fn run_solver(
+    day: &dyn Day,
+    part: u8,
+    solve: impl FnOnce() -> Result<Option<String>, String>,
+) -> Record {
+    let ((result, elapsed), allocations) = memory::measure(|| {
+        let timer = Instant::now();
+        let result = solve();
+        (result, timer.elapsed())
+    });
+    let (answer, error) = match result {
+        Ok(answer) => (answer, None),
+        Err(error) => (None, Some(error)),
+    };
+
+    Record {
+        year: day.year(),
+        day: day.day(),
+        part,
+        answer,
+        error,
+        duration_ns: elapsed.as_nanos(),
+        allocations,
+    }
+}
+
+/// Runs both parts of `day` on `input`
+pub fn run_day(day: &dyn Day, input: &str) -> Vec<Record> {
+    vec![run_part(day, 1, input), run_part(day, 2, input)]
+}
+
+/// Runs every day of `days` on its input file, each part isolated with `timeout`, optionally
+/// running the days side by side, each on its own thread. The rayon thread pool is left to the
+/// solvers, since isolated parts block the thread that waits for them. Days still run one after
+/// another when allocations are counted, so that each part is only charged for its own. Days
+/// without an input file yield no results.
+pub fn run_days(
+    days: &[&'static dyn Day],
+    parallel: bool,
+    timeout: Option<Duration>,
+) -> Vec<(u8, Vec<Isolated<Record>>)> {
+    let run = |day: &&'static dyn Day| {
+        let day = *day;
+        let results = match try_read_input(day.year(), day.day()) {
+            Ok(input) => {
+                let input: Arc<str> = Arc::from(input);
+                (1..=2)
+                    .map(|part| {
+                        let input = Arc::clone(&input);
+                        isolate(timeout, move || run_part(day, part, &input))
+                    })
+                    .collect()
+            }
+            Err(_) => vec![],
+        };
+        (day.day(), results)
+    };
+
+    if parallel && !memory::is_enabled() {
+        thread::scope(|scope| {
+            let handles: Vec<_> = days.iter().map(|day| scope.spawn(|| run(day))).collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    } else {
+        days.iter().map(run).collect()
+    }
+}
+
+/// Time an isolated part gets before it is abandoned, unless told otherwise
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How a piece of work run by `isolate` ended
+#[derive(Clone, Debug, PartialEq)]
+pub enum Isolated<T> {
+    Finished(T),
+    /// The work panicked, with the panic message
+    Panicked(String),
+    /// The work did not finish in time. It keeps running in the background.
+    TimedOut(Duration),
+}
+
+impl<T> Isolated<T> {
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Isolated<U> {
+        match self {
+            Isolated::Finished(value) => Isolated::Finished(f(value)),
+            Isolated::Panicked(message) => Isolated::Panicked(message),
+            Isolated::TimedOut(timeout) => Isolated::TimedOut(timeout),
+        }
+    }
+
+    pub fn finished(&self) -> Option<&T> {
+        match self {
+            Isolated::Finished(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Runs `f` on its own thread, catching panics and giving up after `timeout`. A thread that
+/// times out cannot be stopped, and is left running until the process exits.
+pub fn isolate<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Isolated<T> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        // The receiver is gone when the work timed out
+        sender.send(result).ok();
+    });
+
+    let result = match timeout {
+        Some(timeout) => match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => return Isolated::TimedOut(timeout),
+            Err(RecvTimeoutError::Disconnected) => {
+                return Isolated::Panicked(String::from("the thread exited unexpectedly"))
+            }
+        },
+        None => match receiver.recv() {
+            Ok(result) => result,
+            Err(_) => return Isolated::Panicked(String::from("the thread exited unexpectedly")),
+        },
+    };
+
+    match result {
+        Ok(value) => Isolated::Finished(value),
+        Err(payload) => Isolated::Panicked(panic_message(payload.as_ref())),
+    }
+}
+
+/// Extracts the message of a panic, which is a `&str` or a `String` unless a custom payload was
+/// passed to `panic_any`
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic payload")
+    }
+}
+
+/// How a day went in `cargo all`, see `DayOutcome::of`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DayOutcome {
+    Ok,
+    /// A solver returned an error
+    Failed {
+        part: u8,
+        error: String,
+    },
+    Panicked {
+        part: u8,
+        message: String,
+    },
+    TimedOut {
+        part: u8,
+        timeout: Duration,
+    },
+    /// The day is not registered, or none of its parts returned an answer
+    NotImplemented,
+    /// The day is registered but has no input file
+    NoInput,
+}
+
+impl DayOutcome {
+    /// Classifies the results of the parts of a day, `None` when the day is not registered.
+    /// The first part that went wrong decides.
+    pub fn of(results: Option<&[Isolated<Measurement>]>) -> Self {
+        let Some(results) = results else {
+            return DayOutcome::NotImplemented;
+        };
+        if results.is_empty() {
+            return DayOutcome::NoInput;
+        }
+
+        for (part, result) in (1..).zip(results) {
+            match result {
+                Isolated::Panicked(message) => {
+                    return DayOutcome::Panicked {
+                        part,
+                        message: message.clone(),
+                    }
+                }
+                Isolated::TimedOut(timeout) => {
+                    return DayOutcome::TimedOut {
+                        part,
+                        timeout: *timeout,
+                    }
+                }
+                Isolated::Finished((record, _)) => {
+                    if let Some(error) = &record.error {
+                        return DayOutcome::Failed {
+                            part,
+                            error: error.clone(),
+                        };
+                    }
+                }
+            }
+        }
+
+        let solved = results
+            .iter()
+            .filter_map(Isolated::finished)
+            .any(|(record, _)| record.solved());
+        if solved {
+            DayOutcome::Ok
+        } else {
+            DayOutcome::NotImplemented
+        }
+    }
+
+    /// Whether the day broke, as opposed to not being done yet
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            DayOutcome::Failed { .. } | DayOutcome::Panicked { .. } | DayOutcome::TimedOut { .. }
+        )
+    }
+}
+
+impl Display for DayOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayOutcome::Ok => write!(f, "ok"),
+            DayOutcome::Failed { part, error } => write!(f, "failed in part {part}: {error}"),
+            DayOutcome::Panicked { part, message } => {
+                write!(f, "panicked in part {part}: {message}")
+            }
+            DayOutcome::TimedOut { part, timeout } => {
+                write!(f, "timed out in part {part} after {timeout:.2?}")
+            }
+            DayOutcome::NotImplemented => write!(f, "not implemented"),
+            DayOutcome::NoInput => write!(f, "no input"),
+        }
+    }
+}
+
+/// Prints the answer of `record` with its `verdict`, or its error, and the JSON `Record` when
+/// enabled
+pub fn print_record(record: &Record, verdict: &Verdict) {
+    println!("🎄 {ANSI_BOLD}Part {}{ANSI_RESET} 🎄", record.part);
+    if record.failed() {
+        eprintln!("{}", record.summary());
+    } else {
+        println!("{} {}", record.summary(), verdict);
+    }
+    if record::is_enabled() {
+        println!("{}", record.to_json());
+    }
+}
+
+/// Entry point of the day binaries: solves `day` as told by the `SolveArgs` of the process,
+/// both parts of the input file of the latest season by default. Exits with a non-zero status if
+/// the day is not registered, a solver fails or an answer is wrong.
+pub fn solve_day(day: u8) {
+    let args = match SolveArgs::from_env() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+    let year = args.year;
+
+    let Some(solution) = days::get(year, day) else {
+        eprintln!("Day {day:02} of {year} is not registered, run `cargo scaffold {day:02} --year {year}` first.");
+        process::exit(1);
+    };
+    let input = match args.input.read(year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!(
+                "Could not read {} for day {day:02} of {year}: {e}",
+                args.input
+            );
+            process::exit(1);
+        }
+    };
+
+    if args.variants {
+        compare_variants(solution, &args, &input);
+        return;
+    }
+
+    let mut success = true;
+    for part in args.parts() {
+        let record = run_part(solution, part, &input);
+        // Answers are recorded for the input file only
+        let verdict = match args.input {
+            InputSource::Default => answers::check(&record),
+            _ => Verdict::Unknown,
+        };
+        print_record(&record, &verdict);
+        success &= !record.failed() && !verdict.is_regression();
+    }
+
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// Compares the implementations of each part of `solution` on its examples, the plain one and
+/// the named ones, and on the input of `args`. Each implementation is isolated with the timeout
+/// of `args`. Exits with a non-zero status if they disagree or one of them fails.
+fn compare_variants(solution: &'static dyn Day, args: &SolveArgs, input: &str) {
+    let (year, day) = (solution.year(), solution.day());
+    let plain = fs::read_to_string(get_path("examples", year, day))
+        .ok()
+        .map(|example| (String::from("example"), example));
+    let named = examples::named_examples(year, day)
+        .into_iter()
+        .filter_map(|name| {
+            let path = get_examples_dir(year, day).join(format!("{name}.txt"));
+            let example = fs::read_to_string(path).ok()?;
+            Some((format!("example \"{name}\""), example))
+        });
+    let examples: Vec<(String, String)> = plain
+        .into_iter()
+        .chain(named)
+        .filter(|(_, example)| !example.trim().is_empty())
+        .collect();
+
+    let mut success = true;
+    for part in args.parts() {
+        for (label, example) in &examples {
+            success &= variants::compare(solution, part, label, example, args.timeout);
+        }
+        let label = args.input.to_string();
+        success &= variants::compare(solution, part, &label, input, args.timeout);
+    }
+
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// A `Record` along with its timing `Stats`, if it was benchmarked
+pub type Measurement = (Record, Option<Stats>);
+
+/// Runs one `part` of `day` `iterations` times after a warm-up, and returns the last `Record`
+/// with its duration set to the median timing. Unsolved parts run only once and have no `Stats`.
+pub fn bench_part(day: &dyn Day, part: u8, input: &str, iterations: usize) -> Measurement {
+    let first = run_part(day, part, input);
+    if !first.solved() {
+        return (first, None);
+    }
+
+    let warm_up = (iterations / 10).max(1);
+    (1..warm_up).for_each(|_| {
+        run_part(day, part, input);
+    });
+
+    let mut records: Vec<Record> = (0..iterations.max(1))
+        .map(|_| run_part(day, part, input))
+        .collect();
+    let samples: Vec<_> = records.iter().map(Record::duration).collect();
+    let stats = Stats::from_samples(&samples);
+
+    let mut record = records.pop().unwrap_or(first);
+    if let Some(stats) = stats {
+        record.duration_ns = stats.median.as_nanos();
+    }
+
+    (record, stats)
+}
+
+/// Benchmarks both parts of every day of `days` on its input file, one day after the other.
+/// Each part is isolated, `timeout` covering all of its iterations. Days without an input file
+/// yield no results.
+pub fn bench_days(
+    days: &[&'static dyn Day],
+    iterations: usize,
+    timeout: Option<Duration>,
+) -> Vec<(u8, Vec<Isolated<Measurement>>)> {
+    days.iter()
+        .map(|day| {
+            let day = *day;
+            let results = match try_read_input(day.year(), day.day()) {
+                Ok(input) => {
+                    let input: Arc<str> = Arc::from(input);
+                    (1..=2)
+                        .map(|part| {
+                            let input = Arc::clone(&input);
+                            isolate(timeout, move || bench_part(day, part, &input, iterations))
+                        })
+                        .collect()
+                }
+                Err(_) => vec![],
+            };
+            (day.day(), results)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_run_day() {
+        let day = days::get(2022, 6).unwrap();
+        let records = run_day(day, "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].day, 6);
+        assert_eq!(records[0].part, 1);
+        assert_eq!(records[0].answer.as_deref(), Some("7"));
+        assert_eq!(records[1].part, 2);
+        assert_eq!(records[1].answer.as_deref(), Some("19"));
+    }
+
+    #[test]
+    fn test_bench_part() {
+        let day = days::get(2022, 6).unwrap();
+        let (record, stats) = bench_part(day, 1, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 5);
+        let stats = stats.unwrap();
+
+        assert_eq!(record.answer.as_deref(), Some("7"));
+        assert_eq!(stats.runs, 5);
+        assert_eq!(record.duration_ns, stats.median.as_nanos());
+
+        let day = days::get(2022, 16).unwrap();
+        let (record, stats) = bench_part(day, 1, "", 5);
+        assert!(!record.solved());
+        assert_eq!(stats, None);
+    }
+
+    #[test]
+    fn test_isolate() {
+        assert_eq!(isolate(None, || 42), Isolated::Finished(42));
+        assert_eq!(
+            isolate(None, || -> u8 { panic!("invalid move {}", 3) }),
+            Isolated::Panicked(String::from("invalid move 3"))
+        );
+
+        let timeout = Duration::from_millis(10);
+        let result = isolate(Some(timeout), || thread::sleep(Duration::from_secs(1)));
+        assert_eq!(result, Isolated::TimedOut(timeout));
+    }
+
+    #[test]
+    fn test_day_outcome() {
+        let record = |answer: Option<&str>, error: Option<&str>| {
+            Isolated::Finished((
+                Record {
+                    year: 2022,
+                    day: 1,
+                    part: 1,
+                    answer: answer.map(String::from),
+                    error: error.map(String::from),
+                    duration_ns: 0,
+                    allocations: None,
+                },
+                None,
+            ))
+        };
+        let timeout = Duration::from_secs(1);
+
+        assert_eq!(DayOutcome::of(None), DayOutcome::NotImplemented);
+        assert_eq!(DayOutcome::of(Some(&[])), DayOutcome::NoInput);
+        assert_eq!(
+            DayOutcome::of(Some(&[record(None, None), record(None, None)])),
+            DayOutcome::NotImplemented
+        );
+        assert_eq!(
+            DayOutcome::of(Some(&[record(Some("1"), None), record(None, None)])),
+            DayOutcome::Ok
+        );
+        assert_eq!(
+            DayOutcome::of(Some(&[
+                record(Some("1"), None),
+                Isolated::Panicked(String::from("oops"))
+            ])),
+            DayOutcome::Panicked {
+                part: 2,
+                message: String::from("oops")
+            }
+        );
+        assert_eq!(
+            DayOutcome::of(Some(&[
+                Isolated::TimedOut(timeout),
+                record(None, Some("bad"))
+            ])),
+            DayOutcome::TimedOut { part: 1, timeout }
+        );
+        assert_eq!(
+            DayOutcome::of(Some(&[record(None, Some("bad")), record(None, None)])).to_string(),
+            "failed in part 1: bad"
+        );
+    }
+}
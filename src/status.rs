@@ -0,0 +1,403 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Where a season stands, day by day, as shown by `cargo status`.
+use crate::answers::{self, Verdict};
+use crate::days::{self, Season};
+use crate::helpers::{get_path, try_read_input};
+use crate::history::Entry;
+use crate::record::Record;
+use crate::runner::{self, Isolated};
+use crate::{ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_RED, ANSI_RESET, ANSI_YELLOW};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Outcome of running one part of a day on its input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The day is not registered or has no input
+    NotRun,
+    /// The solver returned `None`
+    Unsolved,
+    /// The solver returned an error, panicked or timed out
+    Failed,
+    Solved(Verdict),
+}
+
+impl PartStatus {
+    fn from_record(record: &Record) -> Self {
+        match (&record.answer, &record.error) {
+            (Some(_), _) => PartStatus::Solved(answers::check(record)),
+            (None, Some(_)) => PartStatus::Failed,
+            (None, None) => PartStatus::Unsolved,
+        }
+    }
+
+    fn from_isolated(result: &Isolated<Record>) -> Self {
+        match result {
+            Isolated::Finished(record) => Self::from_record(record),
+            Isolated::Panicked(_) | Isolated::TimedOut(_) => PartStatus::Failed,
+        }
+    }
+
+    /// Whether the part earned its star, as far as recorded answers tell
+    fn is_star(&self) -> bool {
+        matches!(
+            self,
+            PartStatus::Solved(Verdict::Correct | Verdict::Unknown)
+        )
+    }
+
+    fn is_broken(&self) -> bool {
+        matches!(
+            self,
+            PartStatus::Failed | PartStatus::Solved(Verdict::Wrong { .. })
+        )
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            PartStatus::NotRun => "·",
+            PartStatus::Unsolved => "-",
+            PartStatus::Failed => "!",
+            PartStatus::Solved(Verdict::Correct) => "✓",
+            PartStatus::Solved(Verdict::Unknown) => "?",
+            PartStatus::Solved(Verdict::Wrong { .. }) => "✗",
+        }
+    }
+}
+
+/// Number of tests of a day that pass and fail
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TestResults {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+/// Everything known about one day of a season
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    /// Whether the day has a binary and a registered module
+    pub scaffolded: bool,
+    pub input: bool,
+    pub example: bool,
+    pub puzzle: bool,
+    pub parts: [PartStatus; 2],
+    /// Results of the tests of the module of the day, `None` when it has none or they could not
+    /// be run
+    pub tests: Option<TestResults>,
+    /// Last timing of both parts recorded in the history
+    pub timing: Option<Duration>,
+}
+
+impl DayStatus {
+    /// Number of parts that earned their star
+    pub fn stars(&self) -> usize {
+        self.parts.iter().filter(|part| part.is_star()).count()
+    }
+
+    fn color(&self) -> &'static str {
+        let broken = self.parts.iter().any(PartStatus::is_broken)
+            || self.tests.is_some_and(|results| results.failed > 0);
+
+        match (self.scaffolded, broken, self.stars()) {
+            (false, _, _) => ANSI_DIM,
+            (true, true, _) => ANSI_RED,
+            (true, false, 2) => ANSI_GREEN,
+            _ => ANSI_YELLOW,
+        }
+    }
+}
+
+fn is_filled(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Inspects the files of every day of `season`, runs the tests of the season, and runs the
+/// registered days on their inputs, each part isolated with `timeout`. `history` provides the
+/// last timings.
+pub fn collect(season: &Season, history: &[Entry], timeout: Option<Duration>) -> Vec<DayStatus> {
+    let year = season.year;
+    let tests = run_tests(year);
+
+    // Days run one after the other, a solver using rayon would be starved by days blocking the
+    // threads of the pool while they wait for their parts
+    (1..=25)
+        .map(|day| {
+            let solution = days::get(year, day);
+            let input = try_read_input(year, day)
+                .ok()
+                .filter(|input| !input.is_empty());
+            let parts = match (solution, &input) {
+                (Some(solution), Some(input)) => {
+                    let input: Arc<str> = Arc::from(input.as_str());
+                    (1..=2)
+                        .map(|part| {
+                            let input = Arc::clone(&input);
+                            let result = runner::isolate(timeout, move || {
+                                runner::run_part(solution, part, &input)
+                            });
+                            PartStatus::from_isolated(&result)
+                        })
+                        .collect()
+                }
+                _ => vec![PartStatus::NotRun, PartStatus::NotRun],
+            };
+            let examples_dir = get_path("examples", year, day).with_extension("");
+
+            DayStatus {
+                day,
+                scaffolded: solution.is_some()
+                    && Path::new(&format!("src/bin/{day:02}.rs")).exists(),
+                input: input.is_some(),
+                example: is_filled(&get_path("examples", year, day))
+                    || fs::read_dir(examples_dir).is_ok_and(|mut entries| entries.next().is_some()),
+                puzzle: is_filled(&get_path("puzzles", year, day).with_extension("md")),
+                parts: [parts[0].clone(), parts[1].clone()],
+                tests: tests.as_ref().and_then(|tests| tests.get(&day).copied()),
+                timing: last_timing(history, year, day),
+            }
+        })
+        .collect()
+}
+
+/// Runs the tests of the modules of the season of `year` with `cargo test`, in the build profile
+/// of the running binary. Returns `None` when cargo could not be run.
+fn run_tests(year: u16) -> Option<HashMap<u8, TestResults>> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["test", "--lib"]);
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    let output = cmd
+        .arg(format!("y{year}::"))
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    Some(parse_test_output(
+        &String::from_utf8_lossy(&output.stdout),
+        year,
+    ))
+}
+
+/// Counts the tests that passed and failed for each day in the output of `cargo test`, made of
+/// lines such as `test y2022::day05::tests::test_solve_1 ... ok`
+fn parse_test_output(output: &str, year: u16) -> HashMap<u8, TestResults> {
+    let prefix = format!("test y{year}::day");
+    let mut results: HashMap<u8, TestResults> = HashMap::new();
+
+    for line in output.lines() {
+        let Some((name, outcome)) = line
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.split_once(" ... "))
+        else {
+            continue;
+        };
+        let Some(day) = name.get(..2).and_then(|day| day.parse().ok()) else {
+            continue;
+        };
+
+        let day_results = results.entry(day).or_default();
+        match outcome.trim() {
+            "ok" => day_results.passed += 1,
+            "FAILED" => day_results.failed += 1,
+            _ => {}
+        }
+    }
+
+    results
+}
+
+/// Sums the last recorded timing of each part of `day`
+fn last_timing(history: &[Entry], year: u16, day: u8) -> Option<Duration> {
+    let timings: Vec<u128> = (1..=2)
+        .filter_map(|part| {
+            history
+                .iter()
+                .filter(|entry| entry.year == year && entry.day == day && entry.part == part)
+                .max_by_key(|entry| entry.run)
+                .map(|entry| entry.duration_ns)
+        })
+        .collect();
+
+    (!timings.is_empty()).then(|| Duration::from_nanos(timings.iter().sum::<u128>() as u64))
+}
+
+/// Renders `days` as a calendar of five weeks, each day colored by its state: dim when not
+/// scaffolded, red when broken, green with both stars and yellow in between
+pub fn render_calendar(days: &[DayStatus]) -> String {
+    days.chunks(5)
+        .map(|week| {
+            let cells: Vec<String> = week
+                .iter()
+                .map(|day| {
+                    let stars = day.stars();
+                    format!(
+                        "{}{:>2} {}{}{ANSI_RESET}",
+                        day.color(),
+                        day.day,
+                        "★".repeat(stars),
+                        "☆".repeat(2 - stars)
+                    )
+                })
+                .collect();
+            cells.join("   ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Renders the details of the scaffolded `days` as a table
+pub fn render_table(days: &[DayStatus]) -> String {
+    let check = |value: bool| if value { "✓" } else { "·" };
+    let mut lines = vec![format!(
+        "{ANSI_BOLD}Day  Input  Example  Puzzle  Part 1  Part 2  Tests  Time{ANSI_RESET}"
+    )];
+
+    for day in days.iter().filter(|day| day.scaffolded) {
+        let tests = match day.tests {
+            Some(results) => format!("{}/{}", results.passed, results.passed + results.failed),
+            None => String::from("-"),
+        };
+        let timing = day
+            .timing
+            .map_or(String::from("-"), |timing| format!("{timing:.2?}"));
+
+        lines.push(format!(
+            "{}{:>3}{ANSI_RESET}  {:^5}  {:^7}  {:^6}  {:^6}  {:^6}  {:^5}  {}",
+            day.color(),
+            day.day,
+            check(day.input),
+            check(day.example),
+            check(day.puzzle),
+            day.parts[0].symbol(),
+            day.parts[1].symbol(),
+            tests,
+            timing
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strip_ansi(s: &str) -> String {
+        [
+            ANSI_BOLD,
+            ANSI_DIM,
+            ANSI_GREEN,
+            ANSI_RED,
+            ANSI_YELLOW,
+            ANSI_RESET,
+        ]
+        .iter()
+        .fold(s.to_string(), |s, code| s.replace(code, ""))
+    }
+
+    fn status(day: u8) -> DayStatus {
+        DayStatus {
+            day,
+            scaffolded: false,
+            input: false,
+            example: false,
+            puzzle: false,
+            parts: [PartStatus::NotRun, PartStatus::NotRun],
+            tests: None,
+            timing: None,
+        }
+    }
+
+    #[test]
+    fn test_render_calendar() {
+        let mut days: Vec<DayStatus> = (1..=25).map(status).collect();
+        days[0] = DayStatus {
+            scaffolded: true,
+            parts: [
+                PartStatus::Solved(Verdict::Correct),
+                PartStatus::Solved(Verdict::Unknown),
+            ],
+            ..status(1)
+        };
+        days[1] = DayStatus {
+            scaffolded: true,
+            parts: [PartStatus::Solved(Verdict::Correct), PartStatus::Failed],
+            ..status(2)
+        };
+
+        let calendar = render_calendar(&days);
+        assert_eq!(calendar.lines().count(), 5);
+        assert!(calendar.starts_with(&format!("{ANSI_GREEN} 1 ★★{ANSI_RESET}")));
+        assert!(calendar.contains(&format!("{ANSI_RED} 2 ★☆{ANSI_RESET}")));
+        assert_eq!(
+            strip_ansi(calendar.lines().last().unwrap()),
+            "21 ☆☆   22 ☆☆   23 ☆☆   24 ☆☆   25 ☆☆"
+        );
+    }
+
+    #[test]
+    fn test_parse_test_output() {
+        let output = "running 4 tests
+test y2022::day05::tests::test_solve_1 ... ok
+test y2022::day05::tests::test_solve_2 ... FAILED
+test y2022::day14::tests::test_solve_part_1 ... ok
+test y2022::day15::tests::test_slow ... ignored
+test y2021::day01::tests::test_solve ... ok
+
+test result: FAILED. 2 passed; 1 failed; 1 ignored; 0 measured; 170 filtered out";
+        let results = parse_test_output(output, 2022);
+
+        assert_eq!(
+            results[&5],
+            TestResults {
+                passed: 1,
+                failed: 1
+            }
+        );
+        assert_eq!(
+            results[&14],
+            TestResults {
+                passed: 1,
+                failed: 0
+            }
+        );
+        assert_eq!(results[&15], TestResults::default());
+        assert_eq!(results.len(), 3);
+    }
+
+    #[test]
+    fn test_render_table() {
+        let days = vec![
+            DayStatus {
+                scaffolded: true,
+                input: true,
+                example: true,
+                parts: [PartStatus::Solved(Verdict::Correct), PartStatus::Unsolved],
+                tests: Some(TestResults {
+                    passed: 1,
+                    failed: 1,
+                }),
+                timing: Some(Duration::from_micros(30)),
+                ..status(1)
+            },
+            status(2),
+        ];
+
+        let table = strip_ansi(&render_table(&days));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[1],
+            "  1    ✓       ✓       ·       ✓       -      1/2   30.00µs"
+        );
+    }
+}
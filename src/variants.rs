@@ -0,0 +1,249 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Runs the `Variants` of a part next to its registered implementation, checking that they agree,
+//! for `cargo solve <day> -- --variants`.
+use crate::days::{Day, Variant, DEFAULT_VARIANT};
+use crate::record::Record;
+use crate::runner::{self, isolate, Isolated};
+use crate::{ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Result of one implementation of a part
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariantRun {
+    pub name: &'static str,
+    pub result: Isolated<Record>,
+}
+
+impl VariantRun {
+    /// The answer, or what went wrong instead
+    fn describe(&self) -> String {
+        match &self.result {
+            Isolated::Finished(record) => match (&record.answer, &record.error) {
+                (Some(answer), _) => answer.replace('\n', "\\n"),
+                (None, Some(error)) => format!("failed: {}", error.replace('\n', " ")),
+                (None, None) => String::from("not solved"),
+            },
+            Isolated::Panicked(message) => format!("panicked: {message}"),
+            Isolated::TimedOut(timeout) => format!("timed out after {timeout:.2?}"),
+        }
+    }
+}
+
+/// Runs the registered implementation of `part` of `day` on `input`, then each of its variants,
+/// one after the other. Each run is isolated with `timeout`.
+pub fn run_variants(
+    day: &'static dyn Day,
+    part: u8,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Vec<VariantRun> {
+    let input: Arc<str> = Arc::from(input);
+    let default = {
+        let input = Arc::clone(&input);
+        VariantRun {
+            name: DEFAULT_VARIANT,
+            result: isolate(timeout, move || runner::run_part(day, part, &input)),
+        }
+    };
+
+    let variants = day
+        .variants()
+        .iter()
+        .filter(|variant| variant.part() == part)
+        .map(|variant| {
+            let variant: &'static dyn Variant = *variant;
+            let input = Arc::clone(&input);
+            VariantRun {
+                name: variant.name(),
+                result: isolate(timeout, move || {
+                    runner::run_solver(day, part, || variant.solve(&input))
+                }),
+            }
+        });
+
+    std::iter::once(default).chain(variants).collect()
+}
+
+/// Checks that every run that finished came to the same answer. Runs that panicked or timed out
+/// are left out, they have no answer to compare.
+pub fn check_agreement(runs: &[VariantRun]) -> Result<(), String> {
+    let finished: Vec<&VariantRun> = runs
+        .iter()
+        .filter(|run| run.result.finished().is_some())
+        .collect();
+    let Some(first) = finished.first() else {
+        return Ok(());
+    };
+
+    if finished
+        .iter()
+        .all(|run| run.describe() == first.describe())
+    {
+        Ok(())
+    } else {
+        let answers: Vec<String> = finished
+            .iter()
+            .map(|run| format!("{} = {}", run.name, run.describe()))
+            .collect();
+        Err(format!("variants disagree: {}", answers.join(", ")))
+    }
+}
+
+/// Renders the answers and timings of `runs` as a table, timings relative to the first run
+pub fn render_table(runs: &[VariantRun]) -> String {
+    let baseline = runs
+        .first()
+        .and_then(|run| run.result.finished())
+        .map(Record::duration)
+        .filter(|duration| !duration.is_zero());
+
+    let rows: Vec<[String; 4]> = runs
+        .iter()
+        .map(|run| {
+            let duration = run.result.finished().map(Record::duration);
+            let relative = match (duration, baseline) {
+                (Some(duration), Some(baseline)) => {
+                    format!("{:.2}x", duration.as_secs_f64() / baseline.as_secs_f64())
+                }
+                _ => String::from("-"),
+            };
+
+            [
+                run.name.to_string(),
+                run.describe(),
+                duration.map_or(String::from("-"), |duration| format!("{duration:.2?}")),
+                relative,
+            ]
+        })
+        .collect();
+
+    let header = [
+        String::from("Variant"),
+        String::from("Answer"),
+        String::from("Time"),
+        String::from("Relative"),
+    ];
+    let widths: Vec<usize> = (0..4)
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let render_row = |row: &[String; 4]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<String>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    std::iter::once(format!("{ANSI_BOLD}{}{ANSI_RESET}", render_row(&header)))
+        .chain(rows.iter().map(render_row))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Runs and compares the implementations of `part` of `day` on `input`, and prints the outcome
+/// under `label`. Returns whether all of them finished without error and agreed.
+pub fn compare(
+    day: &'static dyn Day,
+    part: u8,
+    label: &str,
+    input: &str,
+    timeout: Option<Duration>,
+) -> bool {
+    let runs = run_variants(day, part, input, timeout);
+
+    println!("{ANSI_BOLD}Part {part}{ANSI_RESET} on {label}:");
+    println!("{}", render_table(&runs));
+
+    let broken = runs.iter().any(|run| match &run.result {
+        Isolated::Finished(record) => record.failed(),
+        Isolated::Panicked(_) => true,
+        Isolated::TimedOut(_) => false,
+    });
+    let agreement = check_agreement(&runs);
+    match &agreement {
+        Ok(()) if runs.len() > 1 => println!("{ANSI_GREEN}All variants agree.{ANSI_RESET}"),
+        Ok(()) => println!("No variants registered for this part."),
+        Err(e) => eprintln!("{ANSI_RED}{e}{ANSI_RESET}"),
+    }
+    println!();
+
+    !broken && agreement.is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn run(name: &'static str, answer: &str, duration_ns: u128) -> VariantRun {
+        VariantRun {
+            name,
+            result: Isolated::Finished(Record {
+                year: 2022,
+                day: 8,
+                part: 1,
+                answer: Some(answer.to_string()),
+                error: None,
+                duration_ns,
+                allocations: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_run_variants() {
+        let day = days::get(2022, 8).unwrap();
+        let runs = run_variants(day, 1, "30373\n25512\n65332\n33549\n35390", None);
+
+        let names: Vec<&str> = runs.iter().map(|run| run.name).collect();
+        assert_eq!(names, vec![DEFAULT_VARIANT, "scan"]);
+        assert_eq!(check_agreement(&runs), Ok(()));
+        assert_eq!(runs[1].describe(), "21");
+    }
+
+    #[test]
+    fn test_check_agreement() {
+        let timed_out = VariantRun {
+            name: "naive",
+            result: Isolated::TimedOut(Duration::from_secs(1)),
+        };
+        let runs = vec![run(DEFAULT_VARIANT, "26", 10), timed_out];
+        assert_eq!(check_agreement(&runs), Ok(()));
+
+        let runs = vec![run(DEFAULT_VARIANT, "26", 10), run("fast", "27", 5)];
+        assert_eq!(
+            check_agreement(&runs),
+            Err(String::from("variants disagree: default = 26, fast = 27"))
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let runs = vec![
+            run(DEFAULT_VARIANT, "26", 2_000_000),
+            run("intervals", "26", 500_000),
+        ];
+        let table = render_table(&runs)
+            .replace(ANSI_BOLD, "")
+            .replace(ANSI_RESET, "");
+
+        assert_eq!(
+            table,
+            "Variant    Answer  Time      Relative\n\
+             default    26      2.00ms    1.00x\n\
+             intervals  26      500.00µs  0.25x"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Detects changes to the files of a day and compares answers between runs, for `cargo watch-day`.
+use crate::helpers::{get_examples_dir, get_path, get_season_path};
+use crate::record::Record;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Returns the files that affect the answers of `day`: its module and binary, its input, and its
+/// examples, named ones included
+pub fn watched_paths(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut paths = vec![
+        get_season_path(year).join(format!("day{day:02}.rs")),
+        PathBuf::from(format!("src/bin/{day:02}.rs")),
+        get_path("inputs", year, day),
+        get_path("examples", year, day),
+    ];
+
+    let examples = get_examples_dir(year, day);
+    if let Ok(entries) = fs::read_dir(&examples) {
+        let mut named: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+        named.sort();
+        paths.extend(named);
+    }
+    // The folder itself changes when examples are added or removed
+    paths.push(examples);
+
+    paths
+}
+
+/// Metadata of the watched files, missing files included, to tell when any of them changes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot(Vec<(PathBuf, Option<(SystemTime, u64)>)>);
+
+impl Snapshot {
+    /// Reads the modification time and length of every path
+    pub fn take(paths: &[PathBuf]) -> Self {
+        Self(
+            paths
+                .iter()
+                .map(|path| {
+                    let metadata = fs::metadata(path)
+                        .ok()
+                        .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+                    (path.clone(), metadata)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the paths that were created, modified or removed since `previous`
+    pub fn changes(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changes: Vec<PathBuf> = self
+            .0
+            .iter()
+            .filter(|entry| !previous.0.contains(entry))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changes.extend(
+            previous
+                .0
+                .iter()
+                .filter(|(path, _)| !self.0.iter().any(|(other, _)| other == path))
+                .map(|(path, _)| path.clone()),
+        );
+
+        changes
+    }
+}
+
+/// Compares the answers of each part between the `previous` and `current` runs, one line per part
+pub fn diff_answers(previous: &[Record], current: &[Record]) -> Vec<String> {
+    let describe = |record: &Record| match (&record.answer, &record.error) {
+        (Some(answer), _) => answer.replace('\n', "\\n"),
+        (None, Some(_)) => String::from("error"),
+        (None, None) => String::from("none"),
+    };
+
+    current
+        .iter()
+        .map(|record| {
+            let before = previous
+                .iter()
+                .find(|other| other.part == record.part)
+                .map(describe);
+            let after = describe(record);
+
+            match before {
+                Some(before) if before == after => {
+                    format!("Part {}: {after} (unchanged)", record.part)
+                }
+                Some(before) => format!("Part {}: {before} → {after}", record.part),
+                None => format!("Part {}: {after}", record.part),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+
+    fn record(part: u8, answer: Option<&str>, error: Option<&str>) -> Record {
+        Record {
+            year: 2022,
+            day: 9,
+            part,
+            answer: answer.map(String::from),
+            error: error.map(String::from),
+            duration_ns: 0,
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let folder = temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        let (kept, edited) = (folder.join("kept.txt"), folder.join("edited.txt"));
+        fs::write(&kept, "kept").unwrap();
+        fs::write(&edited, "edited").unwrap();
+        let paths = vec![kept, edited.clone(), folder.join("created.txt")];
+
+        let before = Snapshot::take(&paths);
+        assert!(Snapshot::take(&paths).changes(&before).is_empty());
+
+        fs::write(&edited, "edited again").unwrap();
+        fs::write(folder.join("created.txt"), "").unwrap();
+        let after = Snapshot::take(&paths);
+        fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(
+            after.changes(&before),
+            vec![edited, folder.join("created.txt")]
+        );
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let previous = vec![record(1, Some("13"), None), record(2, None, None)];
+        let current = vec![record(1, Some("13"), None), record(2, None, Some("oops"))];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            vec!["Part 1: 13 (unchanged)", "Part 2: none → error"]
+        );
+        assert_eq!(diff_answers(&[], &current[..1]), vec!["Part 1: 13"]);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::helpers::parse::blocks;
+
+/// Solves the problem on the `input` string
+pub fn part_one(input: &str) -> Option<u64> {
+    let elves = parse(input);
+    Some(find_biggest(elves))
+}
+
+pub fn part_two(_input: &str) -> Option<u64> {
+    None
+}
+
+/// Parses the input string into the `Elves` carrying the foods
+pub fn parse(input: &str) -> Vec<Elf> {
+    make_elves(parse_input(input))
+}
+
+/// Makes all our little elves
+fn make_elves(foods: Vec<Vec<u64>>) -> Vec<Elf> {
+    foods
+        .into_iter()
+        .map(|elf_food| Elf { foods: elf_food })
+        .collect()
+}
+
+/// Parses the input string into `Vec<Vec<u64>>`
+fn parse_input(input: &str) -> Vec<Vec<u64>> {
+    blocks(input).map(|(_, block)| parse_block(block)).collect()
+}
+
+/// Parses a block of the input string into `Vec<u64>`
+fn parse_block(block: &str) -> Vec<u64> {
+    block
+        .split("\n")
+        .filter(|token| !token.is_empty())
+        .map(|token| token.parse::<u64>().unwrap())
+        .collect()
+}
+
+/// Describes the `Elf` with its respective calories inside `foods`
+pub struct Elf {
+    foods: Vec<u64>,
+}
+
+/// Provides the `Elf` with `total` for computing its total calories
+impl Elf {
+    pub fn total(&self) -> u64 {
+        let mut total = 0;
+        for food in &self.foods {
+            total += food
+        }
+        total
+    }
+}
+
+/// Find the `Elf` with biggest total calories and return the total
+pub fn find_biggest(elves: Vec<Elf>) -> u64 {
+    let mut max = 0;
+    elves.iter().for_each(|elf| {
+        let total = elf.total();
+        if total >= max {
+            max = total
+        }
+    });
+
+    max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_elf_total() {
+        let elf = Elf {
+            foods: vec![1000, 1500, 3000],
+        };
+        assert!(elf.total() == 5500)
+    }
+
+    #[test]
+    fn test_find_biggest() {
+        let elf1 = Elf { foods: vec![1, 1] };
+        let elf2 = Elf { foods: vec![5, 5] };
+        let elves = vec![elf1, elf2];
+        let biggest = find_biggest(elves);
+        assert!(biggest == 10)
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = &read_example(2022, 1);
+        let tokens = parse_input(input);
+        assert!(tokens[0][0] == 1000);
+        assert!(tokens[0][1] == 2000);
+        assert!(tokens[1][0] == 3000);
+    }
+}
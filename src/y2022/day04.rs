@@ -0,0 +1,174 @@
+use crate::helpers::intervals::RangeSet;
+use std::ops::Range;
+use std::str::FromStr;
+
+pub fn part_one(input: &str) -> Option<u64> {
+    solve(input, Pair::is_fully_overlapping)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    solve(input, Pair::is_overlapping)
+}
+
+fn solve(input: &str, overlap: fn(&Pair) -> bool) -> Option<u64> {
+    let pairs = parse(input);
+    let overlaps = pairs.iter().filter(|pair| overlap(pair));
+    let length = overlaps.count() as u64;
+
+    Some(length)
+}
+
+/// Parses the input string into `Pairs`, one per line
+pub fn parse(input: &str) -> Vec<Pair> {
+    Pair::from_strs(input)
+}
+
+/// Describes a `Pair` of `Assignments`
+pub struct Pair(Assignment, Assignment);
+
+impl Pair {
+    /// Whether the two `Assignments` in the `Pair` are overlapping
+    pub fn is_overlapping(&self) -> bool {
+        self.0.sections().overlaps(&self.1.range())
+    }
+
+    /// Whether one of the two `Assignments` is fully overlapping the other
+    pub fn is_fully_overlapping(&self) -> bool {
+        let two_in_one = self.0.sections().contains_range(&self.1.range());
+        let one_in_two = self.1.sections().contains_range(&self.0.range());
+
+        one_in_two || two_in_one
+    }
+
+    pub fn from_strs(input: &str) -> Vec<Self> {
+        input
+            .lines()
+            .map(|input| input.parse::<Pair>().unwrap())
+            .collect()
+    }
+}
+
+/// Implements parsing `String` into `Pair`
+impl FromStr for Pair {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(',').collect();
+        let assignment_1 = parts[0].parse::<Assignment>().unwrap();
+        let assignment_2 = parts[1].parse::<Assignment>().unwrap();
+
+        Ok(Self(assignment_1, assignment_2))
+    }
+}
+
+/// The sections from the first to the last ID, both included
+pub struct Assignment(u64, u64);
+
+impl Assignment {
+    /// The IDs of the sections, as a `Range`
+    pub fn range(&self) -> Range<u64> {
+        self.0..self.1 + 1
+    }
+
+    /// The IDs of the sections, as a `RangeSet`
+    pub fn sections(&self) -> RangeSet<u64> {
+        RangeSet::from_iter([self.range()])
+    }
+}
+
+/// Implements parsing `String` into `Assignment`
+impl FromStr for Assignment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split('-').collect();
+        let start = parts[0].parse::<u64>().unwrap();
+        let end = parts[1].parse::<u64>().unwrap();
+
+        Ok(Self(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_solve_part_1() {
+        let input = read_example(2022, 4);
+        let result = part_one(&input).unwrap();
+
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let input = read_example(2022, 4);
+        let result = part_two(&input).unwrap();
+
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_pair_is_overlapping() {
+        let pair1 = Pair(Assignment(2, 8), Assignment(3, 7));
+        let pair2 = Pair(Assignment(5, 7), Assignment(7, 9));
+
+        assert!(pair1.is_overlapping());
+        assert!(pair2.is_overlapping());
+    }
+
+    #[test]
+    fn test_pair_is_not_overlapping() {
+        let pair1 = Pair(Assignment(2, 4), Assignment(6, 8));
+        let pair2 = Pair(Assignment(2, 3), Assignment(4, 5));
+
+        assert!(!pair1.is_overlapping());
+        assert!(!pair2.is_overlapping());
+    }
+
+    #[test]
+    fn test_pair_is_fully_overlapping() {
+        let pair = Pair(Assignment(2, 8), Assignment(3, 7));
+
+        assert!(pair.is_fully_overlapping());
+    }
+
+    #[test]
+    fn test_pair_is_not_fully_overlapping() {
+        let pair = Pair(Assignment(5, 7), Assignment(7, 9));
+
+        assert!(!pair.is_fully_overlapping());
+    }
+
+    #[test]
+    fn test_pair_from_multiple() {
+        let input = read_example(2022, 4);
+        let sections = Pair::from_strs(&input);
+
+        assert_eq!(sections[0].0 .0, 2);
+        assert_eq!(sections[1].1 .0, 4);
+        assert_eq!(sections[2].1 .1, 9);
+    }
+
+    #[test]
+    fn test_parse_pair() {
+        let s = "1-10,4-8";
+        let Pair(assignment1, assignment2) = s.parse::<Pair>().unwrap();
+
+        assert_eq!(assignment1.0, 1);
+        assert_eq!(assignment1.1, 10);
+        assert_eq!(assignment2.0, 4);
+        assert_eq!(assignment2.1, 8);
+    }
+
+    #[test]
+    fn test_parse_assignment() {
+        let s = "2-12";
+        let assignment = s.parse::<Assignment>().unwrap();
+
+        assert_eq!(assignment.0, 2);
+        assert_eq!(assignment.1, 12);
+    }
+}
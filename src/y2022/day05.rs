@@ -0,0 +1,361 @@
+use crate::helpers::parse::{blocks, scan, scan_fields};
+use crate::helpers::ParseError;
+use std::str::FromStr;
+
+pub fn part_one(input: &str) -> Result<String, ParseError> {
+    solve(input, Stacks::m_apply)
+}
+
+pub fn part_two(input: &str) -> Result<String, ParseError> {
+    solve(input, Stacks::m_apply_2)
+}
+
+fn solve(input: &str, m_apply: fn(&mut Stacks, Vec<Step>)) -> Result<String, ParseError> {
+    let (mut stacks, steps) = parse(input)?;
+
+    m_apply(&mut stacks, steps);
+    Ok(stacks.get_message())
+}
+
+/// Parses the drawing of the starting `Stacks` and the `Steps` that follow it, checking that every
+/// step moves crates between existing stacks that hold them
+pub fn parse(input: &str) -> Result<(Stacks, Vec<Step>), ParseError> {
+    let mut blocks = blocks(input);
+    let (_, stack_input) = blocks.next().unwrap_or_default();
+    let stack_lines = stack_input.lines().count();
+    let (first_line, steps_input) = blocks.next().ok_or_else(|| {
+        ParseError::at_line(stack_lines + 1, "expected a blank line before the steps")
+    })?;
+
+    let stacks = Stacks::from_str(stack_input)?;
+    let steps = steps_input
+        .parse::<Steps>()
+        .map_err(|e| e.offset_lines(first_line))?;
+
+    // Both parts move the same number of crates, so the sizes of the stacks do not depend on it
+    let mut sizes: Vec<usize> = stacks.0.iter().map(|stack| stack.0.len()).collect();
+    for ((i, line), step) in steps_input.lines().enumerate().zip(&steps.0) {
+        step.check(line, &mut sizes)
+            .map_err(|e| e.offset_lines(first_line + i))?;
+    }
+
+    Ok((stacks, steps.0))
+}
+
+pub struct Steps(pub Vec<Step>);
+
+impl FromStr for Steps {
+    type Err = ParseError;
+
+    /// Parses one `Step` per line. Errors are located relative to the first line of `s`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| line.parse::<Step>().map_err(|e| e.offset_lines(i)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Steps(steps))
+    }
+}
+
+#[derive(Clone)]
+pub struct Step {
+    pub count: u64,
+    pub from: u64,
+    pub to: u64,
+}
+
+const STEP_PATTERN: &str = "move {} from {} to {}";
+
+impl Step {
+    /// Checks that the step, parsed from `line`, can be applied to stacks of the given `sizes`,
+    /// then updates them
+    fn check(&self, line: &str, sizes: &mut [usize]) -> Result<(), ParseError> {
+        let [count, from, to] = scan_fields(STEP_PATTERN, line)?;
+        let stacks = sizes.len();
+        for (index, token) in [(self.from, from), (self.to, to)] {
+            if index == 0 || index > stacks as u64 {
+                let message = format!("no stack {index}, expected 1 to {stacks}");
+                return Err(ParseError::at_token(1, line, token, message));
+            }
+        }
+
+        let (from, to) = (self.from as usize - 1, self.to as usize - 1);
+        if self.count > sizes[from] as u64 {
+            let message = format!(
+                "cannot move {} crates, stack {} holds {}",
+                self.count, self.from, sizes[from]
+            );
+            return Err(ParseError::at_token(1, line, count, message));
+        }
+        sizes[from] -= self.count as usize;
+        sizes[to] += self.count as usize;
+
+        Ok(())
+    }
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (count, from, to) = scan(STEP_PATTERN, s)?;
+
+        Ok(Self { count, from, to })
+    }
+}
+
+#[derive(Clone)]
+pub struct Stacks(Vec<Stack>);
+
+impl Stacks {
+    pub fn get_message(&self) -> String {
+        let chars: Vec<char> = self
+            .0
+            .iter()
+            .filter_map(|stack| stack.get_top_product())
+            .map(|product| product.0)
+            .collect();
+
+        chars.into_iter().collect::<String>()
+    }
+
+    pub fn m_apply(&mut self, steps: Vec<Step>) {
+        steps.iter().for_each(|step| self.apply(step))
+    }
+
+    pub fn m_apply_2(&mut self, steps: Vec<Step>) {
+        steps.iter().for_each(|step| self.apply_2(step))
+    }
+
+    pub fn apply(&mut self, step: &Step) {
+        (0..step.count).for_each(|_| self.move_one(step.from, step.to));
+    }
+
+    pub fn apply_2(&mut self, step: &Step) {
+        let mut src_stack = std::mem::take(&mut self.0[step.from as usize - 1].0);
+        let len = src_stack.len();
+        let start = len - step.count as usize;
+        let products_to_move: Vec<_> = src_stack.splice(start.., vec![]).collect();
+        self.0[step.from as usize - 1].0 = src_stack;
+        self.0[step.to as usize - 1].0.extend(products_to_move);
+    }
+
+    pub fn move_one(&mut self, from: u64, to: u64) {
+        if let Some(product) = self.0[from as usize - 1].0.pop() {
+            self.0[to as usize - 1].0.push(product)
+        }
+    }
+
+    /// Parses the drawing of the stacks, whose last line numbers them
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let numbers = s
+            .lines()
+            .last()
+            .ok_or_else(|| ParseError::at_line(1, "expected the drawing of the stacks"))?;
+        let mut stacks = Stacks::init(numbers);
+
+        s.lines()
+            .rev()
+            .skip(1)
+            .map(parse_line)
+            .for_each(|tokens| stacks.parse_push(tokens));
+
+        Ok(stacks)
+    }
+
+    /// Creates one empty stack per number of `numbers`, the last line of the drawing
+    fn init(numbers: &str) -> Stacks {
+        let n = numbers.split_whitespace().count();
+        Stacks(vec![Stack::new(); n])
+    }
+
+    fn parse_push(&mut self, tokens: Vec<String>) {
+        tokens.iter().enumerate().for_each(|(k, token)| {
+            if let (Ok(product), Some(stack)) = (token.parse::<Product>(), self.0.get_mut(k)) {
+                stack.0.push(product)
+            }
+        })
+    }
+}
+
+fn parse_line(s: &str) -> Vec<String> {
+    let clean_line = s
+        .chars()
+        .enumerate()
+        .filter_map(|(i, c)| if i % 4 == 3 { None } else { Some(c) })
+        .collect::<String>();
+
+    clean_line
+        .chars()
+        .collect::<Vec<_>>()
+        .chunks(3)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct Stack(Vec<Product>);
+
+impl Stack {
+    fn new() -> Stack {
+        Stack(vec![])
+    }
+
+    pub fn get_top_product(&self) -> Option<&Product> {
+        self.0.last()
+    }
+}
+
+#[derive(Clone)]
+pub struct Product(pub char);
+
+impl FromStr for Product {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let char = s.trim().chars().nth(1);
+
+        match char {
+            Some(letter) => Ok(Product(letter)),
+            None => Err(format!("Invalid product {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_solve_1() {
+        let input = read_example(2022, 5);
+        let res = part_one(&input).unwrap();
+
+        assert_eq!(res, "CMZ")
+    }
+
+    #[test]
+    fn test_solve_2() {
+        let input = read_example(2022, 5);
+        let res = part_two(&input).unwrap();
+
+        assert_eq!(res, "MCD")
+    }
+
+    #[test]
+    fn test_solve_invalid_step() {
+        let input = read_example(2022, 5).replace("move 2 from 2 to 1", "move 2 from two to 1");
+        let error = part_one(&input).unwrap_err();
+
+        assert_eq!(error.line, 8);
+        assert_eq!(error.column, 13);
+        assert!(error.render(&input).contains("8 | move 2 from two to 1"));
+    }
+
+    #[test]
+    fn test_solve_invalid_stack() {
+        let input = read_example(2022, 5);
+        for (step, column, message) in [
+            ("move 1 from 0 to 2", 13, "no stack 0, expected 1 to 3"),
+            ("move 1 from 9 to 1", 13, "no stack 9, expected 1 to 3"),
+            ("move 1 from 2 to 4", 18, "no stack 4, expected 1 to 3"),
+        ] {
+            let input = input.replacen("move 1 from 2 to 1", step, 1);
+            assert_eq!(
+                part_one(&input),
+                Err(ParseError::new(6, column, message)),
+                "{step}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_too_many_crates() {
+        // The first step leaves 3 crates on stack 1, so the second one cannot move 5
+        let input = read_example(2022, 5).replace("move 3 from 1 to 3", "move 5 from 1 to 3");
+
+        assert_eq!(
+            part_two(&input),
+            Err(ParseError::new(7, 6, "cannot move 5 crates, stack 1 holds 3"))
+        );
+    }
+
+    #[test]
+    fn test_parse_steps() {
+        let input = read_example(2022, 5);
+        let step_lines = input.split("\n\n").nth(1).unwrap();
+        let steps = step_lines.parse::<Steps>().unwrap();
+
+        assert_eq!(steps.0[0].from, 2);
+        assert_eq!(steps.0[1].count, 3);
+        assert_eq!(steps.0[2].to, 1);
+    }
+
+    #[test]
+    fn test_parse_box() {
+        let s = "[D]";
+        let product = s.parse::<Product>().unwrap();
+
+        assert_eq!(product.0, 'D')
+    }
+
+    #[test]
+    fn test_parse_move() {
+        let s = "move 13 from 1 to 3";
+        let step = s.parse::<Step>().unwrap();
+
+        assert_eq!(step.count, 13);
+        assert_eq!(step.from, 1);
+        assert_eq!(step.to, 3);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let s = "[N] [C]    ";
+        let chunks = parse_line(s);
+
+        assert_eq!(chunks[0], "[N]");
+        assert_eq!(chunks[1], "[C]");
+        assert_eq!(chunks[2], "   ");
+    }
+
+    #[test]
+    fn test_parse_stacks() {
+        let input = read_example(2022, 5);
+        let stacks_input = input.split("\n\n").next().unwrap();
+        let stacks = Stacks::from_str(stacks_input).unwrap();
+
+        assert_eq!(stacks.0[0].0[1].0, 'N');
+        assert_eq!(stacks.0[1].0[2].0, 'D');
+        assert_eq!(stacks.0[2].0[0].0, 'P');
+    }
+
+    #[test]
+    fn test_apply_step() {
+        let input = read_example(2022, 5);
+        let stacks_input = input.split("\n\n").next().unwrap();
+        let mut stacks = Stacks::from_str(stacks_input).unwrap();
+        let step = Step {
+            count: 1,
+            from: 2,
+            to: 1,
+        };
+
+        stacks.apply(&step);
+        assert_eq!(stacks.0[0].0[2].0, 'D');
+        assert_eq!(stacks.0[1].0.len(), 2);
+    }
+
+    #[test]
+    fn test_stacks_get_message() {
+        let input = read_example(2022, 5);
+        let stacks_input = input.split("\n\n").next().unwrap();
+        let stacks = Stacks::from_str(stacks_input).unwrap();
+        let message = stacks.get_message();
+
+        assert_eq!(message, "NDP")
+    }
+}
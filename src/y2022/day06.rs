@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+pub fn part_one(input: &str) -> Option<u64> {
+    find_marker(parse(input), 4)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    find_marker(parse(input), 14)
+}
+
+/// Parses the input string into the datastream, without its line break
+pub fn parse(input: &str) -> &str {
+    input.trim_end()
+}
+
+/// Returns the number of characters read when the first `size` different characters in a row
+/// are received
+pub fn find_marker(input: &str, size: usize) -> Option<u64> {
+    let mut solution: Option<u64> = None;
+    for k in 0..(input.len() - size) {
+        let slice = &input[k..k + size];
+        if is_all_different_chars(slice) {
+            solution = Some((k + size) as u64);
+            break;
+        }
+    }
+
+    solution
+}
+
+pub fn is_all_different_chars(s: &str) -> bool {
+    let mut chars: HashMap<char, usize> = HashMap::new();
+
+    for c in s.chars() {
+        *chars.entry(c).or_insert(0) += 1;
+    }
+
+    !chars.values().any(|&n| n > 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part_one() {
+        let s1 = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        let sol1 = part_one(s1).unwrap();
+        assert_eq!(sol1, 5);
+
+        let s2 = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        let sol2 = part_one(s2).unwrap();
+        assert_eq!(sol2, 11);
+    }
+
+    #[test]
+    fn test_solve_part_two() {
+        let s1 = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let sol1 = part_two(s1).unwrap();
+        assert_eq!(sol1, 19);
+
+        let s2 = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        let sol2 = part_two(s2).unwrap();
+        assert_eq!(sol2, 23);
+    }
+
+    #[test]
+    fn test_is_all_different_chars() {
+        let s1 = "abcd";
+        assert!(is_all_different_chars(s1));
+
+        let s2 = "tufitezo";
+        assert!(!is_all_different_chars(s2));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::helpers::geom::Dir4;
+use crate::helpers::grid::{self, Grid};
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let forest = parse(input);
+    let visible_trees = forest.get_visible_trees();
+    Some(visible_trees.len() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let forest = parse(input);
+    forest
+        .map(|pos| forest.get_scenic_score(pos))
+        .iter()
+        .max()
+        .copied()
+}
+
+/// Same as `part_one`, walking each line of trees once from both of its ends instead of
+/// collecting the hedges of every tree
+pub fn part_one_scan(input: &str) -> Option<u32> {
+    let forest = parse(input);
+    Some(forest.count_visible_from_edges())
+}
+
+/// Same as `part_two`, stepping away from each tree instead of collecting its hedges
+pub fn part_two_walk(input: &str) -> Option<u32> {
+    let forest = parse(input);
+    forest
+        .map(|pos| forest.walk_scenic_score(pos))
+        .iter()
+        .max()
+        .copied()
+}
+
+/// Parses the input string into the `Forest` of tree heights
+pub fn parse(input: &str) -> Forest {
+    Forest::new(input)
+}
+
+/// The heights of the trees. Trees are located by `(row, column)`.
+#[derive(Debug)]
+pub struct Forest(Grid<Tree>);
+pub type Tree = u32;
+impl Forest {
+    /// Build a new `Forest` by parsing the provided `String`
+    pub fn new(s: &str) -> Self {
+        let trees = Grid::parse(s, |c| c.to_digit(10).unwrap()).unwrap();
+
+        Forest(trees)
+    }
+
+    /// Get the list of all visible trees
+    pub fn get_visible_trees(&self) -> Vec<&u32> {
+        self.filter(|pos| self.tree_is_visible(pos))
+    }
+
+    /// Whether the tree at `pos` is visible
+    pub fn tree_is_visible(&self, pos: (u32, u32)) -> bool {
+        Dir4::ALL
+            .iter()
+            .any(|direction| self.tree_is_visible_from(pos, direction))
+    }
+
+    /// Whether the tree at `pos` is visible from `direction`
+    pub fn tree_is_visible_from(&self, pos: (u32, u32), direction: &Dir4) -> bool {
+        let hedge = self.get_hedge(pos, direction);
+        let is_not_visible = hedge.iter().any(|&tree| tree >= self.size(pos));
+
+        !is_not_visible
+    }
+
+    /// Retrieves the size of the tree at `pos`
+    pub fn size(&self, (i, j): (u32, u32)) -> &u32 {
+        &self.0[(j as usize, i as usize)]
+    }
+
+    /// Retrieves the hedge made by trees extending from `pos` in the provided `direction`
+    pub fn get_hedge(&self, pos: (u32, u32), direction: &Dir4) -> Vec<&u32> {
+        self.filter(|(i, j)| match direction {
+            Dir4::Down => i > pos.0 && j == pos.1,
+            Dir4::Left => i == pos.0 && j < pos.1,
+            Dir4::Right => i == pos.0 && j > pos.1,
+            Dir4::Up => i < pos.0 && j == pos.1,
+        })
+    }
+
+    /// Gets the scenic score for tree at `pos`
+    pub fn get_scenic_score(&self, pos: (u32, u32)) -> u32 {
+        Dir4::ALL
+            .map(|direction| self.get_viewing_distance_from(pos, &direction))
+            .into_iter()
+            .reduce(|acc, dist| acc * dist)
+            .unwrap()
+    }
+
+    /// Counts the visible trees by walking every row and column from both ends, keeping track of
+    /// the tallest tree met so far
+    pub fn count_visible_from_edges(&self) -> u32 {
+        let (width, height) = (self.0.width(), self.0.height());
+        let mut visible = Grid::new(width, height, false);
+
+        let mut walk = |line: &mut dyn Iterator<Item = grid::Pos>| {
+            let mut tallest = None;
+            for pos in line {
+                let size = self.0[pos];
+                if Some(size) > tallest {
+                    visible[pos] = true;
+                    tallest = Some(size);
+                }
+            }
+        };
+        for y in 0..height {
+            walk(&mut (0..width).map(|x| (x, y)));
+            walk(&mut (0..width).rev().map(|x| (x, y)));
+        }
+        for x in 0..width {
+            walk(&mut (0..height).map(|y| (x, y)));
+            walk(&mut (0..height).rev().map(|y| (x, y)));
+        }
+
+        visible.find_all(|&visible| visible).count() as u32
+    }
+
+    /// Gets the scenic score for the tree at `pos` by stepping away from it in each direction
+    pub fn walk_scenic_score(&self, (i, j): (u32, u32)) -> u32 {
+        let pos = (j as usize, i as usize);
+        let size = self.0[pos];
+
+        Dir4::ALL
+            .into_iter()
+            .map(|direction| {
+                let mut distance = 0;
+                for (_, &tree) in self.0.ray(pos, direction) {
+                    distance += 1;
+                    if tree >= size {
+                        break;
+                    }
+                }
+                distance
+            })
+            .product()
+    }
+
+    /// Gets the viewing distance from `pos` in `direction`
+    pub fn get_viewing_distance_from(&self, pos: (u32, u32), direction: &Dir4) -> u32 {
+        let hedge = self.get_directed_hedge(pos, direction);
+        let mut distance = 0;
+        for tree in hedge {
+            distance += 1;
+            if tree >= self.size(pos) {
+                break;
+            }
+        }
+        distance as u32
+    }
+
+    /// Useful when wanting to walk the hedge from the center outwards
+    fn get_directed_hedge(&self, pos: (u32, u32), direction: &Dir4) -> Vec<&u32> {
+        let mut hedge = self.get_hedge(pos, direction);
+        if direction == &Dir4::Up || direction == &Dir4::Left {
+            hedge.reverse();
+        }
+        hedge
+    }
+
+    /// Retrieves all the trees matching for the provided `predicate`
+    fn filter<F>(&self, predicate: F) -> Vec<&u32>
+    where
+        F: Fn((u32, u32)) -> bool,
+    {
+        self.0
+            .iter()
+            .filter(|((x, y), _)| predicate((*y as u32, *x as u32)))
+            .map(|(_, tree)| tree)
+            .collect()
+    }
+
+    /// Retrieves all the trees matching for the provided `predicate`
+    fn map<F>(&self, predicate: F) -> Vec<u32>
+    where
+        F: Fn((u32, u32)) -> u32,
+    {
+        self.0
+            .positions()
+            .map(|(x, y)| predicate((y as u32, x as u32)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_solve_part_1() {
+        let input = read_example(2022, 8);
+        let solution = part_one(&input).unwrap();
+        assert_eq!(solution, 21);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let input = read_example(2022, 8);
+        let solution = part_two(&input).unwrap();
+        assert_eq!(solution, 8);
+    }
+
+    #[test]
+    fn test_variants() {
+        let input = read_example(2022, 8);
+
+        assert_eq!(part_one_scan(&input), Some(21));
+        assert_eq!(part_two_walk(&input), Some(8));
+    }
+
+    #[test]
+    fn test_viewing_distance() {
+        let input = read_example(2022, 8);
+        let forest = Forest::new(&input);
+
+        assert_eq!(forest.get_viewing_distance_from((1, 2), &Dir4::Up), 1);
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let input = read_example(2022, 8);
+        let forest = Forest::new(&input);
+
+        assert_eq!(forest.get_scenic_score((0, 0)), 0);
+        assert_eq!(forest.get_scenic_score((1, 2)), 4);
+        assert_eq!(forest.get_scenic_score((3, 2)), 8);
+    }
+
+    #[test]
+    fn test_new_forest() {
+        let input = read_example(2022, 8);
+        let forest = Forest::new(&input);
+
+        assert_eq!(forest.size((0, 2)), &3);
+        assert_eq!(forest.size((1, 4)), &2);
+        assert_eq!(forest.size((2, 0)), &6);
+    }
+
+    #[test]
+    fn test_tree_is_visible() {
+        let input = read_example(2022, 8);
+        let forest = Forest::new(&input);
+
+        assert!(forest.tree_is_visible((0, 0)));
+        assert!(forest.tree_is_visible((1, 1)));
+        assert!(!forest.tree_is_visible((1, 3)));
+        assert!(!forest.tree_is_visible((2, 2)));
+    }
+
+    #[test]
+    fn test_get_hedge() {
+        let input = read_example(2022, 8);
+        let forest = Forest::new(&input);
+
+        let hedge_1 = forest.get_hedge((1, 1), &Dir4::Up);
+        assert_eq!(hedge_1.len(), 1);
+        assert_eq!(hedge_1[0], &0);
+
+        let hedge_2 = forest.get_hedge((3, 3), &Dir4::Left);
+        assert_eq!(hedge_2.len(), 3);
+        assert_eq!(hedge_2[1], &3);
+
+        let hedge_3 = forest.get_hedge((4, 0), &Dir4::Down);
+        assert_eq!(hedge_3.len(), 0);
+    }
+}
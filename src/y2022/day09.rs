@@ -0,0 +1,154 @@
+use crate::helpers::geom::{Dir4, Point2};
+use std::collections::HashMap;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut grid = Grid::new(2);
+    let moves = parse(input);
+    grid.exec_multiple(moves);
+    let solution = grid.get_visited(1);
+
+    Some(solution)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut grid = Grid::new(10);
+    let moves = parse(input);
+    grid.exec_multiple(moves);
+    let solution = grid.get_visited(9);
+
+    Some(solution)
+}
+
+/// Parses the input string into the `Moves` of the head of the rope
+pub fn parse(input: &str) -> Vec<Move> {
+    Move::from(input)
+}
+
+/// A rope made of `nodes`, the first being its head, and the cells each node visited
+pub struct Grid {
+    nodes: Vec<Cell>,
+    visited: Vec<HashMap<Cell, bool>>,
+}
+impl Grid {
+    /// Build a new `Grid` with cells initialized at `(0,0)`
+    pub fn new(nodes: u32) -> Self {
+        let mut visited: Vec<HashMap<Cell, bool>> = (0..nodes).map(|_| HashMap::new()).collect();
+        (0..nodes).for_each(|i| {
+            visited[i as usize].insert(Cell::default(), true);
+        });
+        Self {
+            nodes: (0..nodes).map(|_| Cell::default()).collect(),
+            visited,
+        }
+    }
+
+    /// Retrieves the number of cells that have been visited by node `k`
+    pub fn get_visited(&self, k: u32) -> u32 {
+        self.visited[k as usize].keys().len() as u32
+    }
+
+    /// Executes multiples `Moves` on the `Grid`
+    pub fn exec_multiple(&mut self, moves: Vec<Move>) {
+        moves.iter().for_each(|mv| self.exec(mv))
+    }
+
+    /// Executes a `Move` on the `Grid`
+    pub fn exec(&mut self, mv: &Move) {
+        (0..mv.1).for_each(|_| self.move_once(mv));
+    }
+
+    /// Executes a `Move` once into its `direction` by moving the
+    /// `head` and reconciling the `tail`
+    pub fn move_once(&mut self, mv: &Move) {
+        self.move_node(0, &mv.0);
+        (0..self.nodes.len()).for_each(|k| {
+            self.reconcile_node(k);
+            self.visited[k].insert(self.nodes[k], true);
+        });
+    }
+
+    /// Reconciles the `tail` with the position of the `head`
+    fn reconcile_node(&mut self, k: usize) {
+        if k == 0 {
+            return;
+        }
+        let (head, tail) = (self.nodes[k - 1], self.nodes[k]);
+        if head.chebyshev(tail) > 1 {
+            self.nodes[k] = tail.step_towards(head);
+        }
+    }
+
+    /// Moves the `tail` into `direction`
+    fn move_node(&mut self, k: usize, dir: &Dir4) {
+        self.nodes[k] = self.nodes[k].step(*dir);
+    }
+}
+
+pub type Cell = Point2<i32>;
+
+pub struct Move(pub Dir4, pub u32);
+impl Move {
+    /// Parses lines of an input `str` into a list of `Moves`
+    pub fn from(s: &str) -> Vec<Self> {
+        s.lines().map(Self::from_one).collect()
+    }
+
+    /// Parses a line into a `Move`
+    pub fn from_one(s: &str) -> Self {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        Self(
+            tokens[0].parse::<Dir4>().unwrap(),
+            tokens[1].parse::<u32>().unwrap(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_named_example;
+
+    crate::example_tests!(2022, 9);
+
+    #[test]
+    fn test_parse_move() {
+        let input = read_named_example(2022, 9, "small");
+        let moves = Move::from(&input);
+
+        assert_eq!(moves.len(), 8);
+        assert_eq!(moves[1].0, Dir4::Up);
+        assert_eq!(moves[3].1, 1);
+    }
+
+    #[test]
+    fn test_exec_move() {
+        let mut grid = Grid::new(2);
+        let mv = Move(Dir4::Right, 4);
+        grid.exec(&mv);
+
+        assert_eq!(grid.nodes[0], Cell::new(4, 0));
+        assert_eq!(grid.nodes[1], Cell::new(3, 0));
+    }
+
+    #[test]
+    fn test_move_head() {
+        let mut grid = Grid::new(2);
+        grid.move_node(0, &Dir4::Right);
+        grid.move_node(0, &Dir4::Right);
+        grid.move_node(0, &Dir4::Down);
+
+        // `y` grows downwards
+        assert_eq!(grid.nodes[0], Cell::new(2, 1));
+    }
+
+    #[test]
+    fn test_reconcile_tail() {
+        let mut grid = Grid::new(2);
+        grid.move_node(0, &Dir4::Left);
+        grid.move_node(0, &Dir4::Left);
+        grid.move_node(0, &Dir4::Down);
+        grid.reconcile_node(1);
+
+        assert_eq!(grid.nodes[1], Cell::new(-1, 1));
+    }
+}
@@ -0,0 +1,260 @@
+use crate::helpers::grid::Grid;
+use crate::helpers::ParseError;
+use std::fmt::Display;
+
+pub fn part_one(input: &str) -> Result<i32, ParseError> {
+    let commands = parse(input)?;
+    let mut cpu = Cpu::new();
+    cpu.exec(commands);
+    let solution = cpu.get_strength();
+
+    Ok(solution)
+}
+
+pub fn part_two(input: &str) -> Result<String, ParseError> {
+    let commands = parse(input)?;
+    let mut cpu = Cpu::new();
+    cpu.exec(commands);
+    let screen = Screen::from(cpu);
+    let solution = screen.print();
+
+    Ok(solution)
+}
+
+/// Parses the input string into the `Commands` of the program, one per line
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    Command::from(input)
+}
+
+#[derive(Debug)]
+pub struct Cpu {
+    /// Current value of register `x`
+    register: i32,
+    /// Value of register `x` during each cycle so far
+    x: Vec<i32>,
+}
+impl Cpu {
+    /// Builds a new CPU with `1` set in register `x`
+    pub fn new() -> Self {
+        Self {
+            register: 1,
+            x: vec![1],
+        }
+    }
+
+    /// Retrieves total strength by computing strength
+    pub fn get_strength(&self) -> i32 {
+        let strengths = [20, 60, 100, 140, 180, 220]
+            .map(|k| self.get_strength_at(k))
+            .to_vec();
+        strengths.iter().sum()
+    }
+
+    /// Retrieves the signal strength during cycle `n`
+    pub fn get_strength_at(&self, n: usize) -> i32 {
+        self.get_x_at(n) * (n as i32)
+    }
+
+    /// Retrieves the value of register `x` during cycle `n`. Once the program has ended, the
+    /// register keeps its last value.
+    pub fn get_x_at(&self, n: usize) -> i32 {
+        self.x
+            .get(n.saturating_sub(1))
+            .copied()
+            .unwrap_or(self.register)
+    }
+
+    /// Executes the provided list of `Commands`
+    pub fn exec(&mut self, commands: Vec<Command>) {
+        commands.iter().for_each(|cmd| self.exec_one(cmd));
+    }
+
+    /// Executes the provided `Command`
+    pub fn exec_one(&mut self, command: &Command) {
+        match command {
+            Command::Noop => self.exec_noop(),
+            Command::Addx(dx) => self.exec_addx(dx),
+        }
+    }
+
+    /// Executes a `noop` command
+    fn exec_noop(&mut self) {
+        self.x.push(self.register);
+    }
+
+    /// Executes an `addx` command
+    fn exec_addx(&mut self, dx: &i32) {
+        self.exec_noop();
+        self.register += dx;
+        self.x.push(self.register);
+    }
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Addx(i32),
+    Noop,
+}
+impl Command {
+    /// Parses one `Command` per line
+    pub fn from(s: &str) -> Result<Vec<Self>, ParseError> {
+        s.lines()
+            .enumerate()
+            .map(|(i, line)| Command::from_line(line).map_err(|e| e.offset_lines(i)))
+            .collect()
+    }
+
+    /// Parses a single `Command`. Errors are located on line 1.
+    pub fn from_line(s: &str) -> Result<Self, ParseError> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        match &tokens[..] {
+            ["noop"] => Ok(Command::Noop),
+            ["addx", x] => x
+                .parse::<i32>()
+                .map(Command::Addx)
+                .map_err(|e| ParseError::at_token(1, s, x, e.to_string())),
+            _ => Err(ParseError::at_line(1, "unknown instruction")),
+        }
+    }
+}
+
+pub struct Screen(Grid<Pixel>);
+impl Screen {
+    /// Width of the `Screen` in pixels
+    const WIDTH: usize = 40;
+
+    /// Builds a `Screen` from the values, one pixel per complete row of cycles
+    pub fn from(cpu: Cpu) -> Self {
+        let height = cpu.x.len() / Self::WIDTH;
+        let screen = Grid::from_fn(Self::WIDTH, height, |(column, row)| {
+            let cycle = row * Self::WIDTH + column + 1;
+            Pixel::from(cycle, cpu.get_x_at(cycle))
+        });
+
+        Screen(screen)
+    }
+
+    /// Prints the `Screen` by outputing a `String` containing all printed `Pixels`
+    pub fn print(&self) -> String {
+        format!("{}\n", self.0)
+    }
+}
+
+pub enum Pixel {
+    Lit,
+    Dark,
+}
+impl Pixel {
+    /// Builds a new pixel from the CPU `cycle` value and `x` register value
+    pub fn from(cycle: usize, x: i32) -> Self {
+        let column = ((cycle - 1) % Screen::WIDTH) as i32;
+        let distance = column - x;
+        match distance.abs() {
+            0 | 1 => Self::Lit,
+            _ => Self::Dark,
+        }
+    }
+
+    /// Prints the `Pixel` by converting it into a `char`
+    pub fn print(&self) -> char {
+        match self {
+            Pixel::Dark => '.',
+            Pixel::Lit => '#',
+        }
+    }
+}
+
+impl Display for Pixel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.print())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_parse_instructions() {
+        let input = read_example(2022, 10);
+        let commands = Command::from(&input).unwrap();
+
+        assert_eq!(commands[0], Command::Addx(15));
+        assert_eq!(commands[9], Command::Noop);
+    }
+
+    #[test]
+    fn test_parse_invalid_instruction() {
+        let error = Command::from("noop\naddx 3\naddx x").unwrap_err();
+        assert_eq!(error, ParseError::new(3, 6, "invalid digit found in string"));
+
+        let error = Command::from("noop\nmulx 3").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "unknown instruction"));
+    }
+
+    #[test]
+    fn test_signal_strength() {
+        let input = read_example(2022, 10);
+        let commands = Command::from(&input).unwrap();
+        let mut cpu = Cpu::new();
+        cpu.exec(commands);
+
+        assert_eq!(cpu.get_strength_at(220), 3960);
+    }
+
+    #[test]
+    fn test_exec() {
+        let input = read_example(2022, 10);
+        let commands = Command::from(&input).unwrap();
+        let mut cpu = Cpu::new();
+        cpu.exec(commands);
+
+        assert_eq!(cpu.get_x_at(220), 18);
+    }
+
+    #[test]
+    fn test_exec_short_program() {
+        let mut cpu = Cpu::new();
+        cpu.exec(Command::from("noop\naddx 3\naddx -5").unwrap());
+
+        assert_eq!(cpu.get_x_at(3), 1);
+        assert_eq!(cpu.get_x_at(4), 4);
+        assert_eq!(cpu.get_x_at(5), 4);
+        assert_eq!(cpu.get_x_at(6), -1);
+        assert_eq!(cpu.get_x_at(220), -1);
+        assert_eq!(part_one("noop"), Ok(720));
+        assert_eq!(part_one(""), Ok(720));
+    }
+
+    #[test]
+    fn test_solve_1() {
+        let input = read_example(2022, 10);
+        let solution = part_one(&input).unwrap();
+
+        assert_eq!(solution, 13140)
+    }
+
+    #[test]
+    fn test_print_pixel() {
+        let pixel1 = Pixel::from(8, 11);
+        assert_eq!(pixel1.print(), '.');
+
+        let pixel2 = Pixel::from(13, 12);
+        assert_eq!(pixel2.print(), '#');
+    }
+
+    #[test]
+    fn test_solve_2() {
+        let input = read_example(2022, 10);
+        let solution = part_two(&input).unwrap();
+
+        println!("{}", solution)
+    }
+}
@@ -0,0 +1,356 @@
+use crate::helpers::parse::{blocks, scan, scan_fields};
+use crate::helpers::ParseError;
+
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let mut monkeys = parse(input, true)?;
+    monkeys.run_n_round(20);
+    let solution = monkeys.get_monkey_business();
+
+    Ok(solution)
+}
+
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let mut monkeys = parse(input, false)?;
+    monkeys.run_n_round(10000);
+    let solution = monkeys.get_monkey_business();
+
+    Ok(solution)
+}
+
+/// Parses the input string into `Monkeys`, whose relief after each inspection divides worry
+/// levels by 3 when `with_relief` is set
+pub fn parse(input: &str, with_relief: bool) -> Result<Monkeys, ParseError> {
+    Monkeys::parse(input, with_relief)
+}
+
+#[derive(Debug)]
+pub struct Monkeys(Vec<Monkey>);
+impl Monkeys {
+    /// Parses multiple `Monkeys` from a block of input text, checking that there are at least two
+    /// of them and that they only throw items to each other
+    pub fn parse(text: &str, with_relief: bool) -> Result<Self, ParseError> {
+        let blocks: Vec<(usize, &str)> = blocks(text).collect();
+        if blocks.len() < 2 {
+            let message = format!("expected at least 2 monkeys, found {}", blocks.len());
+            return Err(ParseError::at_line(1, message));
+        }
+
+        let monkeys = blocks
+            .iter()
+            .map(|(first_line, txt)| {
+                Monkey::parse(txt, with_relief)
+                    .and_then(|monkey| monkey.check_targets(txt, blocks.len()).map(|()| monkey))
+                    .map_err(|e| e.offset_lines(*first_line))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self(monkeys))
+    }
+
+    /// Retrieves the current level of monkey business
+    pub fn get_monkey_business(&self) -> usize {
+        let most_active = self.get_n_most_active(2);
+
+        most_active[0] * most_active[1]
+    }
+
+    /// Retrieves the values of `inspected` for `n` most active `Monkeys`
+    pub fn get_n_most_active(&self, n: usize) -> Vec<usize> {
+        let mut inspected: Vec<usize> = self.0.iter().map(|monkey| monkey.inspected).collect();
+        inspected.sort_by(|a, b| b.cmp(a));
+
+        inspected.truncate(n);
+        inspected
+    }
+
+    /// Runs `n` full rounds
+    pub fn run_n_round(&mut self, n: usize) {
+        (0..n).for_each(|_| self.run_round());
+    }
+
+    /// Runs a full round
+    pub fn run_round(&mut self) {
+        (0..self.0.len()).for_each(|k| self.run_monkey_round(k));
+    }
+
+    /// Runs a round for `Monkey` k
+    pub fn run_monkey_round(&mut self, k: usize) {
+        let common_multiple: usize = self.0.iter().map(|m| m.divisible_test).product();
+        let items: Vec<(usize, usize)> = self.0.get_mut(k).unwrap().inspect_all(common_multiple);
+
+        for (k, item) in items {
+            self.0[k].items.push(item);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operation: Operation,
+    divisible_test: usize,
+    target_monkey_test_true: usize,
+    target_monkey_test_false: usize,
+    inspected: usize,
+    with_relief: bool,
+}
+impl Monkey {
+    /// `Monkey` inspects all of its items and returns a list of `(target_money, item)`
+    pub fn inspect_all(&mut self, modulo: usize) -> Vec<(usize, usize)> {
+        (0..self.items.len())
+            .map(|_| self.inspect(modulo))
+            .collect()
+    }
+
+    /// `Monkey` inspects the first item in its list and returns `(target_monkey, item)`
+    pub fn inspect(&mut self, modulo: usize) -> (usize, usize) {
+        let item = self.items.remove(0);
+        let new = if self.with_relief {
+            self.worry(item) / 3
+        } else {
+            self.worry(item) % modulo
+        };
+        self.inspected += 1;
+
+        if new % self.divisible_test == 0 {
+            (self.target_monkey_test_true, new)
+        } else {
+            (self.target_monkey_test_false, new)
+        }
+    }
+
+    /// Takes an `item` as input and update worry level
+    fn worry(&mut self, item: usize) -> usize {
+        match self.operation {
+            Operation::Add(x) => item + x,
+            Operation::AddOld => item + item,
+            Operation::Mult(x) => item * x,
+            Operation::MultOld => item * item,
+        }
+    }
+
+    /// Parses a `Monkey` from a block of input text. Errors are located relative to the
+    /// first line of the block.
+    fn parse(txt: &str, with_relief: bool) -> Result<Self, ParseError> {
+        let parsers: [LineParser; 5] = [
+            Monkey::parse_items,
+            Monkey::parse_operation,
+            Monkey::parse_divisible_test,
+            Monkey::parse_throw_monkey,
+            Monkey::parse_throw_monkey,
+        ];
+        let mut monkey = Monkey::new(with_relief);
+        let mut lines = txt.lines().skip(1);
+
+        for (k, parser) in parsers.iter().enumerate() {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::at_line(k + 1, "incomplete monkey"))?;
+            parser(&mut monkey, line).map_err(|e| e.offset_lines(k + 1))?;
+        }
+
+        Ok(monkey)
+    }
+
+    /// Checks that the `Monkey` throws its items to one of the `monkeys` monkeys. Errors are
+    /// located relative to the first line of the block.
+    fn check_targets(&self, txt: &str, monkeys: usize) -> Result<(), ParseError> {
+        let targets = [self.target_monkey_test_true, self.target_monkey_test_false];
+        for ((k, line), target) in txt.lines().enumerate().skip(4).zip(targets) {
+            if target >= monkeys {
+                let token = line.rsplit(' ').next().unwrap_or(line);
+                let message = format!("no monkey {target}, expected 0 to {}", monkeys - 1);
+                return Err(ParseError::at_token(k + 1, line, token, message));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds a blank `Monkey`. Should not be used.
+    fn new(with_relief: bool) -> Self {
+        Self {
+            items: vec![],
+            operation: Operation::AddOld,
+            divisible_test: 0,
+            target_monkey_test_true: 0,
+            target_monkey_test_false: 0,
+            inspected: 0,
+            with_relief,
+        }
+    }
+
+    /// Parses a line defining the starting items
+    fn parse_items(&mut self, line: &str) -> Result<(), ParseError> {
+        let [items_txt] = scan_fields("Starting items: {}", line)?;
+        self.items = items_txt
+            .split(", ")
+            .map(|token| parse_number(line, token))
+            .collect::<Result<_, _>>()?;
+
+        Ok(())
+    }
+
+    /// Parses a line defining the `Operation`
+    fn parse_operation(&mut self, line: &str) -> Result<(), ParseError> {
+        let [operation_txt] = scan_fields("Operation: new = old {}", line)?;
+        self.operation = Operation::from(operation_txt).ok_or_else(|| {
+            ParseError::at_token(1, line, operation_txt, "unknown operation")
+        })?;
+
+        Ok(())
+    }
+
+    /// Parses a line defining the divisible test
+    fn parse_divisible_test(&mut self, line: &str) -> Result<(), ParseError> {
+        let [divisor] = scan_fields("Test: divisible by {}", line)?;
+        self.divisible_test = parse_number(line, divisor)?;
+        if self.divisible_test == 0 {
+            return Err(ParseError::at_token(1, line, divisor, "cannot divide by 0"));
+        }
+
+        Ok(())
+    }
+
+    /// Parses a line defining how to throw objects after test
+    fn parse_throw_monkey(&mut self, line: &str) -> Result<(), ParseError> {
+        let (test, monkey): (bool, usize) = scan("If {}: throw to monkey {}", line)?;
+
+        if test {
+            self.target_monkey_test_true = monkey;
+        } else {
+            self.target_monkey_test_false = monkey;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses one of the lines describing a `Monkey`
+type LineParser = fn(&mut Monkey, &str) -> Result<(), ParseError>;
+
+/// Parses `token`, a number found in `line`
+fn parse_number(line: &str, token: &str) -> Result<usize, ParseError> {
+    token
+        .parse::<usize>()
+        .map_err(|e| ParseError::at_token(1, line, token, e.to_string()))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Operation {
+    Add(usize),
+    AddOld,
+    Mult(usize),
+    MultOld,
+}
+impl Operation {
+    /// Parses a string defining an `Operation`
+    pub fn from(s: &str) -> Option<Self> {
+        let mut tokens = s.split_whitespace();
+        match (tokens.next(), tokens.next()) {
+            (Some("*"), Some("old")) => Some(Operation::MultOld),
+            (Some("+"), Some("old")) => Some(Operation::AddOld),
+            (Some("*"), Some(x)) => x.parse::<usize>().ok().map(Operation::Mult),
+            (Some("+"), Some(x)) => x.parse::<usize>().ok().map(Operation::Add),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_parse_monkeys() {
+        let example = read_example(2022, 11);
+        let monkeys = Monkeys::parse(&example, true).unwrap();
+
+        assert_eq!(monkeys.0.len(), 4);
+        assert_eq!(monkeys.0[0].items.len(), 2);
+        assert_eq!(monkeys.0[1].operation, Operation::Add(6));
+        assert_eq!(monkeys.0[2].divisible_test, 13);
+        assert_eq!(monkeys.0[3].target_monkey_test_true, 0);
+        assert_eq!(monkeys.0[3].target_monkey_test_false, 1);
+    }
+
+    #[test]
+    fn test_parse_invalid_monkey() {
+        let example = read_example(2022, 11).replace("Operation: new = old + 6", "Operation: new = old / 6");
+        let error = Monkeys::parse(&example, true).unwrap_err();
+
+        assert_eq!(error, ParseError::new(10, 24, "unknown operation"));
+    }
+
+    #[test]
+    fn test_parse_divide_by_zero() {
+        let example = read_example(2022, 11).replace("divisible by 13", "divisible by 0");
+        let error = Monkeys::parse(&example, true).unwrap_err();
+
+        assert_eq!(error, ParseError::new(18, 22, "cannot divide by 0"));
+    }
+
+    #[test]
+    fn test_parse_unknown_target() {
+        let example = read_example(2022, 11).replace("If false: throw to monkey 1", "If false: throw to monkey 4");
+        let error = Monkeys::parse(&example, true).unwrap_err();
+
+        assert_eq!(error, ParseError::new(27, 31, "no monkey 4, expected 0 to 3"));
+    }
+
+    #[test]
+    fn test_parse_too_few_monkeys() {
+        let example = read_example(2022, 11);
+        let first = example.split("\n\n").next().unwrap();
+        let error = Monkeys::parse(first, true).unwrap_err();
+
+        assert_eq!(error, ParseError::at_line(1, "expected at least 2 monkeys, found 1"));
+        assert!(part_one("").is_err());
+    }
+
+    #[test]
+    fn test_worry() {
+        let example = read_example(2022, 11);
+        let mut monkeys = Monkeys::parse(&example, true).unwrap();
+        let monkey = monkeys.0.get_mut(0).unwrap();
+
+        let (target1, item1) = monkey.inspect(1);
+        assert_eq!(target1, 3);
+        assert_eq!(item1, 500);
+
+        let (target2, item2) = monkey.inspect(1);
+        assert_eq!(target2, 3);
+        assert_eq!(item2, 620);
+    }
+
+    #[test]
+    fn test_run_one_round() {
+        let example = read_example(2022, 11);
+        let mut monkeys = Monkeys::parse(&example, true).unwrap();
+        monkeys.run_round();
+
+        assert_eq!(monkeys.0[0].items.len(), 4);
+        assert_eq!(monkeys.0[0].items[0], 20);
+        assert_eq!(monkeys.0[1].items.len(), 6);
+        assert_eq!(monkeys.0[1].items[0], 2080);
+        assert_eq!(monkeys.0[2].items.len(), 0);
+        assert_eq!(monkeys.0[3].items.len(), 0);
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        let example = read_example(2022, 11);
+        let solution = part_one(&example).unwrap();
+
+        assert_eq!(solution, 10605)
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let example = read_example(2022, 11);
+        let solution = part_two(&example).unwrap();
+
+        assert_eq!(solution, 2713310158)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::helpers::grid::{self, Grid};
+use crate::helpers::search::{bfs, Found};
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let mountains = parse(input);
+    let starts = mountains.find_lowest();
+    let end = mountains.find_end().unwrap();
+
+    mountains.shortest_path(starts, end).map(|found| found.cost)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let mountains = parse(input);
+    let start = mountains.find_start().unwrap();
+    let end = mountains.find_end().unwrap();
+
+    mountains.shortest_path([start], end).map(|found| found.cost)
+}
+
+/// Parses the input string into the `MountainsBag` heightmap
+pub fn parse(input: &str) -> MountainsBag {
+    MountainsBag::parse(input)
+}
+
+/// The heightmap. Mountains are located by `Position(row, column)`.
+#[derive(Debug)]
+pub struct MountainsBag(Grid<Mountain>);
+impl MountainsBag {
+    pub fn parse(s: &str) -> Self {
+        Self(Grid::parse(s, Mountain::parse).unwrap())
+    }
+
+    pub fn get(&self, pos: Position) -> &Mountain {
+        &self.0[pos.into()]
+    }
+
+    pub fn find_lowest(&self) -> Vec<Position> {
+        self.0
+            .find_all(|mountain| mountain.height == 1)
+            .map(Position::from)
+            .collect()
+    }
+
+    pub fn find_start(&self) -> Option<Position> {
+        self.0.find(|mountain| mountain.start).map(Position::from)
+    }
+
+    pub fn find_end(&self) -> Option<Position> {
+        self.0.find(|mountain| mountain.end).map(Position::from)
+    }
+
+    /// Shortest path from the closest of `starts` to `to`
+    pub fn shortest_path(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        to: Position,
+    ) -> Option<Found<Position, usize>> {
+        bfs(
+            starts,
+            |&from| self.get_paths_from(from),
+            |&position| position == to,
+        )
+    }
+
+    /// Positions that can be climbed to from `from`
+    pub fn get_paths_from(&self, from: Position) -> impl Iterator<Item = Position> + '_ {
+        self.0
+            .neighbours4(from.into())
+            .map(Position::from)
+            .filter(move |to| self.is_possible_path(&from, to))
+    }
+
+    pub fn is_possible_path(&self, from: &Position, to: &Position) -> bool {
+        let mountain_from = self.get(*from);
+        let mountain_to = self.get(*to);
+
+        mountain_from.height() >= mountain_to.height() - 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position(pub usize, pub usize);
+impl From<grid::Pos> for Position {
+    fn from((x, y): grid::Pos) -> Self {
+        Position(y, x)
+    }
+}
+impl From<Position> for grid::Pos {
+    fn from(Position(i, j): Position) -> Self {
+        (j, i)
+    }
+}
+#[derive(Debug)]
+pub struct Mountain {
+    height: usize,
+    start: bool,
+    end: bool,
+}
+impl Mountain {
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn parse(c: char) -> Self {
+        let alphabet = "abcdefghijklmnopqrstuvwxyz".chars();
+        match alphabet.enumerate().find(|(_, char)| c == *char) {
+            Some((elevation, _)) => Self {
+                height: elevation + 1,
+                start: false,
+                end: false,
+            },
+            None => {
+                if c == 'S' {
+                    Self {
+                        height: 1,
+                        start: true,
+                        end: false,
+                    }
+                } else {
+                    // This is "E"
+                    Self {
+                        height: 26,
+                        start: false,
+                        end: true,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_parse_mountains() {
+        let example = read_example(2022, 12);
+        let mountains = MountainsBag::parse(&example);
+
+        assert_eq!(mountains.get(Position(0, 0)).height(), 1);
+        assert_eq!(mountains.get(Position(4, 2)).height(), 4);
+    }
+
+    #[test]
+    fn test_get_paths_from() {
+        let example = read_example(2022, 12);
+        let mountains_bag = MountainsBag::parse(&example);
+        let paths = mountains_bag
+            .0
+            .positions()
+            .map(|pos| mountains_bag.get_paths_from(Position::from(pos)).count())
+            .sum::<usize>();
+
+        assert_eq!(paths, 111)
+    }
+
+    #[test]
+    fn test_path() {
+        let example = read_example(2022, 12);
+        let mountains_bag = MountainsBag::parse(&example);
+
+        let path_1 = mountains_bag
+            .shortest_path([Position(0, 0)], Position(1, 0))
+            .unwrap();
+        assert_eq!(path_1.cost, 1);
+
+        let path_3 = mountains_bag
+            .shortest_path([Position(0, 0)], Position(3, 2))
+            .unwrap();
+        assert_eq!(path_3.cost, 5);
+        assert_eq!(path_3.path.len(), 6);
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        let example = read_example(2022, 12);
+        let solution = part_one(&example).unwrap();
+
+        assert_eq!(solution, 31);
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let example = read_example(2022, 12);
+        let solution = part_two(&example).unwrap();
+
+        assert_eq!(solution, 29);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::helpers::geom::{Dir4, Point2};
+use crate::helpers::grid::SparseGrid;
+use std::fmt::Display;
+
+/// Solves the part 1 of the puzzle. Inserts sand until map is full. Floor deactivated.
+/// Return number of sand grains inserted.
+pub fn part_one(input: &str) -> Option<usize> {
+    let mut map = parse(input, false);
+
+    while map.insert_sand() {}
+
+    Some(map.sands())
+}
+
+/// Solves the part 2 of the puzzle. Inserts sand until map is full. Floor activated.
+/// Return number of sand grains inserted.
+pub fn part_two(input: &str) -> Option<usize> {
+    let mut map = parse(input, true);
+
+    while map.insert_sand() {}
+
+    Some(map.sands())
+}
+
+/// Parses the input string into the `Map` of rocks, with a floor under the lowest rock when
+/// `with_floor` is set
+pub fn parse(input: &str, with_floor: bool) -> Map {
+    Map::build(input, with_floor)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Rock,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    floor: Option<i64>,
+    /// Y coordinate of the lowest rock
+    bottom: i64,
+    pub cave: SparseGrid<Tile>,
+    source: Position,
+}
+
+impl Map {
+    /// Number of sand grains at rest in the cave
+    pub fn sands(&self) -> usize {
+        self.cave
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Sand)
+            .count()
+    }
+
+    /// Inserts a new sand from the source. Returns `true` if the sand was correctly inserted.
+    /// Returns `false` if it fell indefinitely.
+    pub fn insert_sand(&mut self) -> bool {
+        if self.cave.contains(self.source) {
+            return false;
+        }
+
+        if let Some(sand) = self.fall_from(&self.source) {
+            self.cave.insert(sand, Tile::Sand);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Falls from `Position`. Returns `None` if it falls indefinitely. Returns the `Position`
+    /// where it should fall to otherwise.
+    pub fn fall_from(&self, pos: &Position) -> Option<Position> {
+        if let Some(first_obstacle) = self.find_obstacle_under(pos) {
+            if self.find_obstacle_left(&first_obstacle).is_some() {
+                if self.find_obstacle_right(&first_obstacle).is_some() {
+                    Some(first_obstacle.step(Dir4::Up))
+                } else {
+                    self.fall_from(&first_obstacle.step(Dir4::Right))
+                }
+            } else {
+                self.fall_from(&first_obstacle.step(Dir4::Left))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// May find a rock left to the provided `Position`
+    fn find_obstacle_left(&self, coord: &Position) -> Option<Position> {
+        let left_pos = coord.step(Dir4::Left);
+        if self.cave.contains(left_pos) || Some(coord.y) == self.floor {
+            Some(left_pos)
+        } else {
+            None
+        }
+    }
+
+    /// May find a rock right to the provided `Position`
+    fn find_obstacle_right(&self, coord: &Position) -> Option<Position> {
+        let right_pos = coord.step(Dir4::Right);
+        if self.cave.contains(right_pos) || Some(coord.y) == self.floor {
+            Some(right_pos)
+        } else {
+            None
+        }
+    }
+
+    /// May find a rock under the provided `Position`
+    fn find_obstacle_under(&self, coord: &Position) -> Option<Position> {
+        // Sand may rest under the lowest rock, on the floor
+        let lowest = self.floor.map_or(self.bottom, |floor| floor - 1);
+        (coord.y + 1..=lowest)
+            .map(|y| Position::new(coord.x, y))
+            .find(|&pos| self.cave.contains(pos))
+            .or_else(|| self.floor.map(|floor| Position::new(coord.x, floor)))
+    }
+
+    /// Builds the `Map` from the `str` input
+    pub fn build(input: &str, with_floor: bool) -> Self {
+        let mut map = Self {
+            floor: None,
+            bottom: 0,
+            cave: SparseGrid::new(),
+            source: Position::new(500, 0),
+        };
+
+        input.lines().map(Self::parse_line).for_each(|coords| {
+            (0..coords.len() - 1).for_each(|k| {
+                let from = coords.get(k).unwrap();
+                let to = coords.get(k + 1).unwrap();
+                map.insert_rocks(*from, *to);
+            })
+        });
+
+        map.bottom = map.cave.bounds().map_or(0, |(_, bottom_right)| bottom_right.y);
+        if with_floor {
+            map.floor = map.find_floor();
+        }
+
+        map
+    }
+
+    /// Finds the Y coordinate of the floor
+    pub fn find_floor(&self) -> Option<i64> {
+        Some(self.bottom + 2)
+    }
+
+    /// Insert rocks in the map between the provided `Positions`
+    fn insert_rocks(&mut self, from: Position, to: Position) {
+        let x_range = from.x.min(to.x)..=from.x.max(to.x);
+        let y_range = from.y.min(to.y)..=from.y.max(to.y);
+        x_range.for_each(|x| {
+            self.cave.insert(Position::new(x, from.y), Tile::Rock);
+        });
+        y_range.for_each(|y| {
+            self.cave.insert(Position::new(from.x, y), Tile::Rock);
+        });
+    }
+
+    /// Parses a `Position` boundary defined by "X0,Y0 -> X1,Y1"
+    fn parse_line(input: &str) -> Vec<Position> {
+        input.split(" -> ").map(Map::parse_rock).collect()
+    }
+
+    /// Parses a `Position` defined by "X,Y" as `str`
+    fn parse_rock(input: &str) -> Position {
+        let coords: Vec<i64> = input
+            .split(',')
+            .map(|token| token.parse::<i64>().unwrap())
+            .collect();
+
+        Position::new(*coords.first().unwrap(), *coords.get(1).unwrap())
+    }
+}
+
+pub type Position = Point2<i64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_parse_input() {
+        let input = read_example(2022, 14);
+        let map = Map::build(&input, false);
+
+        assert_eq!(map.cave.len(), 20);
+
+        let rock1 = map.cave.get(Position::new(498, 5));
+        assert_eq!(rock1, Some(&Tile::Rock));
+        let rock2 = map.cave.get(Position::new(500, 9));
+        assert_eq!(rock2, Some(&Tile::Rock));
+        let no_rock = map.cave.get(Position::new(500, 2));
+        assert_eq!(no_rock, None);
+    }
+
+    #[test]
+    fn test_rock_under() {
+        let input = read_example(2022, 14);
+        let map = Map::build(&input, false);
+
+        let rock1 = map.find_obstacle_under(&map.source).unwrap();
+        assert_eq!(rock1, Position::new(500, 9));
+        let rock2 = map.find_obstacle_under(&Position::new(498, 0)).unwrap();
+        assert_eq!(rock2, Position::new(498, 4));
+        let no_rock = map.find_obstacle_under(&Position::new(493, 0));
+        assert_eq!(no_rock, None);
+    }
+
+    #[test]
+    fn test_insert_sand() {
+        let input = read_example(2022, 14);
+        let mut map = Map::build(&input, false);
+
+        map.insert_sand(); // Insert 1st sand grain
+        assert_eq!(map.cave.get(Position::new(500, 8)), Some(&Tile::Sand));
+
+        map.insert_sand(); // Insert 2nd sand grain
+        assert_eq!(map.cave.get(Position::new(499, 8)), Some(&Tile::Sand));
+
+        assert!(map.insert_sand()); // Insert 3rd sand grain
+        assert!(map.insert_sand()); // Insert 4th sand grain
+        assert!(map.insert_sand()); // Insert 5th sand grain
+        assert_eq!(map.cave.get(Position::new(500, 7)), Some(&Tile::Sand));
+        assert_eq!(map.sands(), 5);
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        let input = read_example(2022, 14);
+        let solution = part_one(&input).unwrap();
+
+        assert_eq!(solution, 24)
+    }
+
+    #[test]
+    fn test_find_floor() {
+        let input = read_example(2022, 14);
+        let map = Map::build(&input, true);
+
+        assert_eq!(map.find_floor().unwrap(), 11)
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let input = read_example(2022, 14);
+        let solution = part_two(&input).unwrap();
+
+        assert_eq!(solution, 93)
+    }
+}
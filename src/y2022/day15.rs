@@ -0,0 +1,297 @@
+use crate::helpers::geom::Point2;
+use crate::helpers::intervals::RangeSet;
+use crate::helpers::parse::{ints, lines};
+use crate::helpers::ParseError;
+use rayon::prelude::*;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Row whose covered cells part 1 counts. The example asks about row 10 instead.
+pub const ROW: isize = 2000000;
+
+/// Largest coordinate of the area part 2 searches for the distress beacon. The example searches
+/// up to 20 instead.
+pub const SEARCH_SIZE: isize = 4000000;
+
+pub fn part_one(input: &str) -> Result<Option<usize>, ParseError> {
+    let map = parse(input)?;
+    let solution = map.count_covered_row_intervals(ROW);
+
+    Ok(Some(solution))
+}
+
+/// Same as `part_one`, checking every cell of the row instead of merging the ranges covered by
+/// each sensor
+pub fn part_one_scan(input: &str) -> Result<Option<usize>, ParseError> {
+    let map = parse(input)?;
+    let solution = map.count_covered_row(ROW);
+
+    Ok(Some(solution))
+}
+
+pub fn part_two(input: &str) -> Result<Option<isize>, ParseError> {
+    let map = parse(input)?;
+    let solution = map
+        .find_distress_beacon(SEARCH_SIZE)
+        .map(|beacon| tuning_frequency(&beacon));
+
+    Ok(solution)
+}
+
+/// Parses the input string into the `Map` of `Sensors`
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    input.parse()
+}
+
+pub struct Map {
+    sensors: Vec<Sensor>,
+    x_max: isize,
+    x_min: isize,
+}
+impl From<Vec<Sensor>> for Map {
+    fn from(sensors: Vec<Sensor>) -> Self {
+        let x_min = sensors
+            .iter()
+            .map(|sensor| sensor.pos.x - sensor.distance_to_closest)
+            .min()
+            .unwrap();
+        let x_max = sensors
+            .iter()
+            .map(|sensor| sensor.pos.x + sensor.distance_to_closest)
+            .max()
+            .unwrap();
+
+        Self {
+            sensors,
+            x_max,
+            x_min,
+        }
+    }
+}
+impl FromStr for Map {
+    type Err = ParseError;
+
+    /// Parses one `Sensor` per line. The map needs at least one of them.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let positions = parse_positions(s)?;
+        if positions.is_empty() {
+            return Err(ParseError::at_line(1, "expected at least one sensor"));
+        }
+        let sensors: Vec<Sensor> = positions.iter().map(Sensor::from).collect();
+
+        Ok(Map::from(sensors))
+    }
+}
+
+impl Map {
+    /// Counts the number of cells covered on row `y`
+    pub fn count_covered_row(&self, y: isize) -> usize {
+        self.walk_row(y)
+            .filter(|cell| self.is_covered(cell))
+            .count()
+    }
+
+    /// Counts the number of cells covered on row `y`, from the ranges each sensor covers
+    pub fn count_covered_row_intervals(&self, y: isize) -> usize {
+        let covered = self.covered_row(y).len() as usize;
+
+        // Beacons are not counted, unless covered by a sensor they are not the closest to
+        let mut beacons: Vec<Position> = self
+            .sensors
+            .iter()
+            .map(|sensor| sensor.closest_beacon)
+            .filter(|beacon| beacon.y == y && !self.is_covered(beacon))
+            .collect();
+        beacons.sort_by_key(|beacon| beacon.x);
+        beacons.dedup();
+
+        covered - beacons.len()
+    }
+
+    /// Cells of row `y` within reach of a sensor, beacons included
+    pub fn covered_row(&self, y: isize) -> RangeSet<isize> {
+        self.sensors
+            .iter()
+            .filter_map(|sensor| sensor.covered_on_row(y))
+            .collect()
+    }
+
+    /// Finds a `Position` within the ranges that no sensor reaches, searching the rows for a gap
+    /// between the ranges the sensors cover. Returns `None` when the sensors cover them entirely.
+    pub fn find_not_covered(
+        &self,
+        x_range: RangeInclusive<isize>,
+        y_range: RangeInclusive<isize>,
+    ) -> Option<Position> {
+        let columns = *x_range.start()..*x_range.end() + 1;
+        y_range.into_par_iter().find_map_any(|y| {
+            let gap = self.covered_row(y).gaps(columns.clone()).next()?;
+            Some(Position { x: gap.start, y })
+        })
+    }
+
+    /// Finds the distress beacon, the only `Position` no sensor reaches with both coordinates
+    /// between 0 and `size`
+    pub fn find_distress_beacon(&self, size: isize) -> Option<Position> {
+        self.find_not_covered(0..=size, 0..=size)
+    }
+
+    /// Returns whether the provided `Position` is covered by one of the `Map` sensors
+    pub fn is_covered(&self, pos: &Position) -> bool {
+        self.sensors.iter().any(|sensor| sensor.not_beacon(pos))
+    }
+
+    /// Returns whether the provided `Position` is not covered by any of the `Map` sensors
+    pub fn is_not_covered(&self, pos: &Position) -> bool {
+        self.sensors
+            .iter()
+            .all(|sensor| sensor.possible_beacon(pos))
+    }
+
+    /// Walks the line defined by `y` up to the boundaries of the `Map`
+    fn walk_row(&self, y: isize) -> impl Iterator<Item = Position> + '_ {
+        (self.x_min..=self.x_max).map(move |x| Position { x, y })
+    }
+}
+
+pub struct Sensor {
+    closest_beacon: Position,
+    distance_to_closest: isize,
+    pos: Position,
+}
+impl From<&(Position, Position)> for Sensor {
+    fn from((sensor, beacon): &(Position, Position)) -> Self {
+        let distance_to_closest = sensor.manhattan(*beacon);
+
+        Self {
+            closest_beacon: *beacon,
+            distance_to_closest,
+            pos: *sensor,
+        }
+    }
+}
+impl Sensor {
+    /// Whether the provided `Position` cannot be a beacon
+    pub fn not_beacon(&self, pos: &Position) -> bool {
+        !self.is_detected(pos) && self.is_covered(pos)
+    }
+
+    /// Whether the provided `Position` can be a unknown beacon
+    pub fn possible_beacon(&self, pos: &Position) -> bool {
+        !self.is_detected(pos) && !self.is_covered(pos)
+    }
+
+    /// Returns the range of row `y` covered by the sensor, if any
+    pub fn covered_on_row(&self, y: isize) -> Option<RangeInclusive<isize>> {
+        let reach = self.distance_to_closest - (self.pos.y - y).abs();
+        (reach >= 0).then(|| self.pos.x - reach..=self.pos.x + reach)
+    }
+
+    /// Returns whether the provided `Position` is covered
+    pub fn is_covered(&self, pos: &Position) -> bool {
+        self.pos.manhattan(*pos) <= self.distance_to_closest
+    }
+
+    /// Returns whether the provided `Position` is detected as a beacon
+    pub fn is_detected(&self, pos: &Position) -> bool {
+        pos == &self.closest_beacon
+    }
+}
+
+pub type Position = Point2<isize>;
+
+/// Parses the `Positions` of each sensor and of its closest beacon, one pair per line
+pub fn parse_positions(input: &str) -> Result<Vec<(Position, Position)>, ParseError> {
+    lines(input, |line| {
+        let [sensor_x, sensor_y, beacon_x, beacon_y] = ints::<4>(line)?;
+
+        Ok((
+            Position::new(sensor_x as isize, sensor_y as isize),
+            Position::new(beacon_x as isize, beacon_y as isize),
+        ))
+    })
+}
+
+/// Computes the tuning frequency for the `Position`
+pub fn tuning_frequency(position: &Position) -> isize {
+    position.x * 4000000 + position.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_parse() {
+        let example = read_example(2022, 15);
+        let positions = parse_positions(&example).unwrap();
+
+        assert_eq!(positions.len(), 14);
+        assert_eq!(positions[3].0.x, 12);
+        assert_eq!(positions[5].1.y, 16);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let example = read_example(2022, 15);
+        let input = example.replacen("y=16", "y=sixteen", 1);
+
+        let error = parse(&input).err().unwrap();
+        assert_eq!(error.line, 2);
+        let error = parse("").err().unwrap();
+        assert_eq!(error, ParseError::at_line(1, "expected at least one sensor"));
+    }
+
+    #[test]
+    fn test_distance() {
+        let sensor = Position { x: 8, y: 7 };
+        let beacon = Position { x: 2, y: 10 };
+
+        assert_eq!(sensor.manhattan(beacon), 9);
+    }
+
+    #[test]
+    fn test_solve_part_1() {
+        let example = read_example(2022, 15);
+        let map: Map = example.parse().unwrap();
+        let solution = map.count_covered_row(10);
+
+        assert_eq!(solution, 26);
+    }
+
+    #[test]
+    fn test_solve_part_1_intervals() {
+        let example = read_example(2022, 15);
+        let map: Map = example.parse().unwrap();
+
+        for y in 0..=20 {
+            assert_eq!(map.count_covered_row_intervals(y), map.count_covered_row(y));
+        }
+        assert_eq!(map.count_covered_row_intervals(10), 26);
+    }
+
+    #[test]
+    fn test_solve_example() {
+        let example = read_example(2022, 15);
+
+        let map = parse(&example).unwrap();
+
+        assert_eq!(map.count_covered_row_intervals(10), 26);
+        assert_eq!(map.count_covered_row(10), 26);
+        let beacon = map.find_distress_beacon(20).unwrap();
+        assert_eq!(tuning_frequency(&beacon), 56000011);
+        // The solvers ask about the row and area of the puzzle input
+        assert_eq!(part_one(&example), part_one_scan(&example));
+    }
+
+    #[test]
+    fn test_solve_part_2() {
+        let example = read_example(2022, 15);
+        let map: Map = example.parse().unwrap();
+
+        let not_covered = map.find_not_covered(0..=20, 0..=20).unwrap();
+        let tun_freq = tuning_frequency(&not_covered);
+        assert_eq!(tun_freq, 56000011);
+    }
+}
@@ -0,0 +1,18 @@
+/// Parses the input string into its lines, until the puzzle is solved
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part_one(_input: &str) -> Option<u64> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test() {}
+}
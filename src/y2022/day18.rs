@@ -0,0 +1,90 @@
+use crate::helpers::geom::Point3;
+use crate::helpers::parse::ints;
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let droplet = parse(input);
+    Some(droplet.surface())
+}
+
+pub fn part_two(_input: &str) -> Option<usize> {
+    None
+}
+
+/// Parses the input string into the `LavaDroplet` made of its `LavaPixels`
+pub fn parse(input: &str) -> LavaDroplet {
+    LavaDroplet::from(input)
+}
+
+pub struct LavaDroplet {
+    pixels: Vec<LavaPixel>,
+}
+impl From<&str> for LavaDroplet {
+    /// Builds a `LavaDroplet` from a `str` list of `LavaPixels`
+    fn from(value: &str) -> Self {
+        let pixels = value.lines().map(parse_pixel).collect();
+        Self { pixels }
+    }
+}
+impl LavaDroplet {
+    /// Returns the surface of the `LavaDroplet`
+    pub fn surface(&self) -> usize {
+        self.pixels
+            .iter()
+            .map(|pixel| 6 - self.count_adjacents(pixel))
+            .sum()
+    }
+
+    /// Return number of `LavaPixels` that are adjacent to provided `pixel`
+    pub fn count_adjacents(&self, pixel: &LavaPixel) -> usize {
+        self.pixels
+            .iter()
+            .filter(|lhs| lhs.manhattan(*pixel) == 1)
+            .count()
+    }
+}
+
+/// A cube of lava
+pub type LavaPixel = Point3;
+
+/// Parses a `LavaPixel` from the input `str` "x,y,z"
+pub fn parse_pixel(value: &str) -> LavaPixel {
+    let [x, y, z] = ints::<3>(value).unwrap();
+
+    Point3::new(x, y, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::read_example;
+
+    #[test]
+    fn test_parse_lava() {
+        let example = read_example(2022, 18);
+        let droplet = LavaDroplet::from(example.as_str());
+
+        assert_eq!(droplet.pixels.len(), 13);
+    }
+
+    #[test]
+    fn test_are_adjacent() {
+        let example = read_example(2022, 18);
+        let droplet = LavaDroplet::from(example.as_str());
+
+        let pixel_0 = droplet.pixels.first().unwrap();
+        let pixel_1 = droplet.pixels.get(1).unwrap();
+        let pixel_2 = droplet.pixels.get(2).unwrap();
+
+        assert_eq!(pixel_0.manhattan(*pixel_1), 1);
+        assert_eq!(pixel_0.manhattan(*pixel_2), 1);
+        assert_ne!(pixel_1.manhattan(*pixel_2), 1);
+    }
+
+    #[test]
+    fn test_surface() {
+        let example = read_example(2022, 18);
+        let droplet = LavaDroplet::from(example.as_str());
+
+        assert_eq!(droplet.surface(), 64);
+    }
+}
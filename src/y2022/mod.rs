@@ -0,0 +1,27 @@
+/*
+ * This file contains template code.
+ * New days are registered in the `days!` invocation below. `cargo scaffold` does that for you.
+ * Variants of a part are listed on the line of their day, see `crate::days::days`.
+ */
+use crate::days::days;
+
+days! {
+    year 2022;
+    1 => day01,
+    2 => day02,
+    3 => day03,
+    4 => day04,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08 { 1: [scan => part_one_scan], 2: [walk => part_two_walk] },
+    9 => day09,
+    10 => day10,
+    11 => day11,
+    12 => day12,
+    13 => day13,
+    14 => day14,
+    15 => day15 { 1: [scan => part_one_scan] },
+    16 => day16,
+    18 => day18,
+}
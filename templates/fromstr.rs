@@ -0,0 +1,41 @@
+//! {{title}}
+use crate::helpers::ParseError;
+use std::str::FromStr;
+
+/// One line of the input
+#[derive(Debug, PartialEq, Eq)]
+pub struct Entry {}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    /// Parses a line. Errors are located on line 1, `parse` moves them to the actual line.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Err(ParseError::at_token(1, line, line, "not parsed yet"))
+    }
+}
+
+/// Parses every line of the input into an `Entry`
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| line.parse().map_err(|e: ParseError| e.offset_lines(index)))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let entries = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let entries = parse(input)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    // One test per answer listed in "src/y{{year}}/examples/{{day_padded}}/answers.txt"
+    crate::example_tests!({{year}}, {{day}});
+}
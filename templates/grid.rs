@@ -0,0 +1,24 @@
+//! {{title}}
+use crate::helpers::grid::Grid;
+use crate::helpers::ParseError;
+
+/// Parses the input string into the map of the puzzle, one `char` per cell
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| c)
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let grid = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let grid = parse(input)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    // One test per answer listed in "src/y{{year}}/examples/{{day_padded}}/answers.txt"
+    crate::example_tests!({{year}}, {{day}});
+}
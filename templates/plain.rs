@@ -0,0 +1,23 @@
+//! {{title}}
+use crate::helpers::ParseError;
+
+/// Parses the input string, one item per line
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(input.lines().collect())
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let lines = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let lines = parse(input)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    // One test per answer listed in "src/y{{year}}/examples/{{day_padded}}/answers.txt"
+    crate::example_tests!({{year}}, {{day}});
+}
@@ -0,0 +1,49 @@
+//! {{title}}
+use crate::helpers::ParseError;
+
+/// State of the simulation
+#[derive(Clone, Debug)]
+pub struct State {}
+
+impl State {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {})
+    }
+
+    /// Advances the simulation by one step. Returns whether anything changed.
+    pub fn step(&mut self) -> bool {
+        false
+    }
+
+    /// Steps until nothing changes anymore. Returns the number of steps taken.
+    pub fn run(&mut self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+        steps
+    }
+}
+
+/// Parses the input string into the initial `State`
+pub fn parse(input: &str) -> Result<State, ParseError> {
+    State::parse(input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let mut state = parse(input)?;
+    state.run();
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{answer}}>, ParseError> {
+    let mut state = parse(input)?;
+    state.run();
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    // One test per answer listed in "src/y{{year}}/examples/{{day_padded}}/answers.txt"
+    crate::example_tests!({{year}}, {{day}});
+}